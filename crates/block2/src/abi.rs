@@ -25,7 +25,7 @@ impl BlockFlags {
     const BLOCK_DEALLOCATING: Self = Self(0x0001);
 
     /// Note: Not public ABI.
-    const BLOCK_REFCOUNT_MASK: Self = Self(if cfg!(feature = "gnustep-1-7") {
+    pub(crate) const BLOCK_REFCOUNT_MASK: Self = Self(if cfg!(feature = "gnustep-1-7") {
         // Mask for the reference count in byref structure's flags field. The low
         // 3 bytes are reserved for the reference count, the top byte for the flags.
         0x00ffffff
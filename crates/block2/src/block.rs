@@ -117,6 +117,55 @@ impl<F: ?Sized> Block<F> {
     }
 }
 
+impl<F: ?Sized> Block<F> {
+    /// The current reference count of the block.
+    ///
+    /// Blocks on the stack or in global memory are not reference counted,
+    /// and return `0`. Heap blocks (such as those in an [`RcBlock`]) start
+    /// out at `1`, and the count is bumped by [`Block::copy`] / `Clone`.
+    ///
+    /// This is a diagnostic intended for debugging and for testing that
+    /// blocks are not leaked, and should not be relied upon for anything
+    /// else; the count may change concurrently, and the precise value
+    /// depends on the runtime's internals.
+    ///
+    /// Only available with the `"unstable-retain-count"` feature.
+    #[cfg(any(test, feature = "unstable-retain-count"))]
+    #[doc(alias = "retainCount")]
+    pub fn retain_count(&self) -> usize {
+        use core::ptr;
+        use core::sync::atomic::{AtomicI32, Ordering};
+
+        use crate::abi::BlockFlags;
+        use crate::debug::Isa;
+
+        let isa = Isa(self.header().isa);
+        if isa.is_global() || isa.is_stack() {
+            return 0;
+        }
+
+        let ptr: NonNull<Self> = NonNull::from(self);
+        let ptr: *const BlockHeader = ptr.cast().as_ptr();
+
+        // The runtime updates the reference count atomically, so we must
+        // read it atomically as well to avoid a data race.
+        //
+        // SAFETY: The pointer is valid, and `AtomicI32` has the same size
+        // and alignment as `c_int` (which is `i32` on all supported
+        // platforms). `BlockFlags` is `#[repr(transparent)]`.
+        if cfg!(feature = "gnustep-1-7") {
+            // GNUStep initializes the `reserved` field when copying the
+            // block to the heap, since it's used for the reference count.
+            let count: *const AtomicI32 = unsafe { ptr::addr_of!((*ptr).reserved) }.cast();
+            unsafe { &*count }.load(Ordering::Relaxed) as usize
+        } else {
+            let flags: *const AtomicI32 = unsafe { ptr::addr_of!((*ptr).flags) }.cast();
+            let flags = unsafe { &*flags }.load(Ordering::Relaxed);
+            ((flags & BlockFlags::BLOCK_REFCOUNT_MASK.0) >> 1) as usize
+        }
+    }
+}
+
 impl<F: ?Sized> fmt::Debug for Block<F> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut f = f.debug_struct("Block");
@@ -6,17 +6,17 @@ use crate::abi::{BlockDescriptorPtr, BlockFlags, BlockHeader};
 use crate::ffi;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
-struct Isa(*const ffi::Class);
+pub(crate) struct Isa(pub(crate) *const ffi::Class);
 
 impl Isa {
-    fn is_global(self) -> bool {
+    pub(crate) fn is_global(self) -> bool {
         ptr::eq(
             unsafe { ptr::addr_of!(ffi::_NSConcreteGlobalBlock) },
             self.0,
         )
     }
 
-    fn is_stack(self) -> bool {
+    pub(crate) fn is_stack(self) -> bool {
         ptr::eq(unsafe { ptr::addr_of!(ffi::_NSConcreteStackBlock) }, self.0)
     }
 }
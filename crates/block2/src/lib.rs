@@ -164,6 +164,37 @@
 //! ```
 //!
 //!
+//! ## Returning objects
+//!
+//! Blocks use the C ABI, so objects are passed to and from them as raw
+//! pointers. The convention is that a block returns its object autoreleased,
+//! i.e. the caller does not receive ownership of it.
+//!
+//! To follow this in Rust, return `*mut T` from the closure, and use
+//! [`Id::autorelease_return`] to give up ownership of the object without
+//! deallocating it. Conversely, when invoking such a block, use
+//! [`Id::retain_autoreleased`] to take ownership of the returned object.
+//!
+//! ```
+//! use block2::RcBlock;
+//! use objc2::rc::{autoreleasepool, Id};
+//! use objc2::runtime::NSObject;
+//!
+//! let block = RcBlock::new(|| Id::autorelease_return(NSObject::new()));
+//!
+//! autoreleasepool(|_| {
+//!     let _obj: Id<NSObject> = unsafe { Id::retain_autoreleased(block.call(())) }.unwrap();
+//! });
+//! ```
+//!
+//! Note that returning [`Id<T>`] directly from the closure is not possible,
+//! since it does not have a defined C ABI.
+//!
+//! [`Id<T>`]: objc2::rc::Id
+//! [`Id::autorelease_return`]: objc2::rc::Id::autorelease_return
+//! [`Id::retain_autoreleased`]: objc2::rc::Id::retain_autoreleased
+//!
+//!
 //! ## Lifetimes
 //!
 //! When dealing with blocks, there can be quite a few lifetimes to keep in
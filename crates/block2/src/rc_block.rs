@@ -265,4 +265,27 @@ mod tests {
         assert_eq!(block.call((10,)), 55);
         assert_eq!(block.call((19,)), 4181);
     }
+
+    #[test]
+    fn retain_count() {
+        let block = RcBlock::new(|| 42);
+        assert_eq!(block.retain_count(), 1);
+
+        let cloned = block.clone();
+        assert_eq!(block.retain_count(), 2);
+        assert_eq!(cloned.retain_count(), 2);
+
+        let copied = cloned.copy();
+        assert_eq!(block.retain_count(), 3);
+
+        drop(cloned);
+        drop(copied);
+        assert_eq!(block.retain_count(), 1);
+
+        let stack = StackBlock::new(|| 42);
+        assert_eq!(stack.retain_count(), 0);
+        let copied = stack.copy();
+        assert_eq!(stack.retain_count(), 0);
+        assert_eq!(copied.retain_count(), 1);
+    }
 }
@@ -4,6 +4,7 @@ use std::fmt;
 use clang::{Entity, PlatformAvailability, Version};
 
 use crate::context::Context;
+use crate::display_helper::FormatterFn;
 
 #[derive(Debug, Clone, PartialEq, Default)]
 struct Unavailable {
@@ -17,6 +18,24 @@ struct Unavailable {
     visionos: bool,
 }
 
+impl Unavailable {
+    /// The `target_os` values that the item is unavailable on.
+    ///
+    /// TODO: Handle Mac Catalyst (needs `target_abi`) and app extensions.
+    fn target_oses(&self) -> impl Iterator<Item = &'static str> {
+        [
+            (self.macos, "macos"),
+            (self.ios, "ios"),
+            (self.tvos, "tvos"),
+            (self.watchos, "watchos"),
+            (self.visionos, "visionos"),
+        ]
+        .into_iter()
+        .filter(|(unavailable, _)| *unavailable)
+        .map(|(_, target_os)| target_os)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Default)]
 struct Versions {
     ios: Option<Version>,
@@ -29,7 +48,7 @@ struct Versions {
     visionos: Option<Version>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Availability {
     unavailable: Unavailable,
     introduced: Versions,
@@ -155,6 +174,45 @@ impl Availability {
             }
         )
     }
+
+    /// Whether the item is unavailable on all of the platforms that we
+    /// support, in which case there's no reason to emit it at all.
+    ///
+    /// Mac Catalyst inherits the availability of iOS, so it doesn't need to
+    /// be checked separately.
+    pub fn is_unavailable_everywhere(&self) -> bool {
+        let Unavailable {
+            ios,
+            macos,
+            watchos,
+            tvos,
+            visionos,
+            ..
+        } = self.unavailable;
+        ios && macos && watchos && tvos && visionos
+    }
+
+    /// A `#[cfg(...)]`-gate that removes the item on the platforms where it
+    /// is unavailable (and would fail to link).
+    pub fn cfg_gate_ln(&self) -> impl fmt::Display + '_ {
+        FormatterFn(move |f| {
+            let target_oses: Vec<_> = self.unavailable.target_oses().collect();
+            match &*target_oses {
+                [] => Ok(()),
+                [target_os] => writeln!(f, "#[cfg(not(target_os = \"{target_os}\"))]"),
+                target_oses => {
+                    write!(f, "#[cfg(not(any(")?;
+                    for (i, target_os) in target_oses.iter().enumerate() {
+                        if i != 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "target_os = \"{target_os}\"")?;
+                    }
+                    writeln!(f, ")))]")
+                }
+            }
+        })
+    }
 }
 
 impl fmt::Display for Availability {
@@ -173,8 +231,74 @@ impl fmt::Display for Availability {
                 }
             }
         }
-        // TODO: Emit `cfg` attributes based on `self.unavailable`
         // TODO: Emit availability checks based on `self.introduced`
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unavailable(unavailable: Unavailable) -> Availability {
+        Availability {
+            unavailable,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unavailable() {
+        let availability = unavailable(Unavailable::default());
+        assert!(!availability.is_unavailable_everywhere());
+        assert_eq!(availability.cfg_gate_ln().to_string(), "");
+
+        // API_UNAVAILABLE(macos)
+        let availability = unavailable(Unavailable {
+            macos: true,
+            ..Default::default()
+        });
+        assert!(!availability.is_unavailable_everywhere());
+        assert_eq!(
+            availability.cfg_gate_ln().to_string(),
+            "#[cfg(not(target_os = \"macos\"))]\n",
+        );
+
+        // API_UNAVAILABLE(ios, tvos)
+        let availability = unavailable(Unavailable {
+            ios: true,
+            tvos: true,
+            ..Default::default()
+        });
+        assert!(!availability.is_unavailable_everywhere());
+        assert_eq!(
+            availability.cfg_gate_ln().to_string(),
+            "#[cfg(not(any(target_os = \"ios\", target_os = \"tvos\")))]\n",
+        );
+
+        // API_UNAVAILABLE(macos, ios, watchos, tvos)
+        let availability = unavailable(Unavailable {
+            macos: true,
+            ios: true,
+            watchos: true,
+            tvos: true,
+            ..Default::default()
+        });
+        assert!(!availability.is_unavailable_everywhere());
+        assert_eq!(
+            availability.cfg_gate_ln().to_string(),
+            "#[cfg(not(any(target_os = \"macos\", target_os = \"ios\", target_os = \"tvos\", target_os = \"watchos\")))]\n",
+        );
+
+        // API_UNAVAILABLE(macos, ios, watchos, tvos, visionos)
+        let availability = unavailable(Unavailable {
+            macos: true,
+            ios: true,
+            watchos: true,
+            tvos: true,
+            visionos: true,
+            ..Default::default()
+        });
+        assert!(availability.is_unavailable_everywhere());
+    }
+}
@@ -95,6 +95,19 @@ pub struct LibraryConfig {
     #[serde(rename = "custom-lib-rs")]
     #[serde(default)]
     pub custom_lib_rs: bool,
+    /// Fold the methods of categories into the `extern_methods!` block of
+    /// the class itself, when both are declared in the same file.
+    ///
+    /// Categories on classes from other libraries are still emitted as
+    /// separate traits, and protocol conformances are unaffected.
+    #[serde(rename = "merge-categories")]
+    #[serde(default)]
+    pub merge_categories: bool,
+    /// Name methods after their `NS_SWIFT_NAME(...)`, converted to snake
+    /// case, instead of after their selector.
+    #[serde(rename = "swift-names")]
+    #[serde(default)]
+    pub swift_names: bool,
 
     #[serde(default)]
     #[serde(deserialize_with = "get_version")]
@@ -167,6 +180,16 @@ pub struct ClassData {
     #[serde(rename = "skipped-protocols")]
     #[serde(default)]
     pub skipped_protocols: HashSet<String>,
+    /// The path to the CoreFoundation type that this class is toll-free
+    /// bridged with, if any.
+    #[serde(rename = "bridged-to")]
+    #[serde(default)]
+    pub bridged_to: Option<String>,
+    /// Don't implement `DefaultId` for the class, even if it has a usable
+    /// `new` or `init` method.
+    #[serde(rename = "default-id-skipped")]
+    #[serde(default)]
+    pub default_id_skipped: bool,
 }
 
 impl ClassData {
@@ -42,6 +42,9 @@ data! {
         // The other string is non-null, and won't be retained
         unsafe -stringByAppendingString:;
         unsafe -stringByAppendingPathComponent:;
+        unsafe -lastPathComponent;
+        unsafe -pathExtension;
+        unsafe -stringByDeletingLastPathComponent;
         // Assuming `NSStringEncoding` can be made safe
         unsafe -lengthOfBytesUsingEncoding:;
         unsafe -length;
@@ -49,6 +52,14 @@ data! {
         unsafe -UTF8String;
         unsafe -initWithString:;
         unsafe +stringWithString:;
+        // The separator is non-null, and the result is a new array
+        unsafe -componentsSeparatedByString:;
+        unsafe -uppercaseString;
+        unsafe -lowercaseString;
+        unsafe -capitalizedString;
+        unsafe -stringByTrimmingCharactersInSet:;
+        unsafe -componentsSeparatedByCharactersInSet:;
+        unsafe -rangeOfString:;
     }
 
     class NSMutableString: MutableWithImmutableSuperclass<Foundation::NSString::NSString> {
@@ -85,6 +96,10 @@ data! {
     class NSBundle {
         unsafe +mainBundle;
         unsafe -infoDictionary;
+        unsafe -bundleIdentifier;
+        // Returns `nil` if the resource is not found
+        unsafe -pathForResource:ofType:;
+        unsafe -objectForInfoDictionaryKey:;
     }
 
     class NSData: ImmutableWithMutableSubclass<Foundation::NSData::NSMutableData> {
@@ -125,6 +140,8 @@ data! {
         unsafe -code;
         unsafe -userInfo;
         unsafe -localizedDescription;
+        unsafe -localizedFailureReason;
+        unsafe -localizedRecoverySuggestion;
     }
 
     class NSException {
@@ -165,6 +182,16 @@ data! {
         unsafe +processInfo;
         unsafe -processName;
         unsafe -operatingSystemVersion;
+        unsafe -arguments;
+        unsafe -environment;
+        unsafe -hostName;
+    }
+
+    class NSLocale {
+        unsafe +currentLocale;
+        unsafe +localeWithLocaleIdentifier:;
+        unsafe -localeIdentifier;
+        unsafe -languageCode;
     }
 
     class NSSet: ImmutableWithMutableSubclass<Foundation::NSSet::NSMutableSet> {
@@ -177,7 +204,15 @@ data! {
         unsafe -removeAllObjects;
     }
 
-    class NSCharacterSet: ImmutableWithMutableSubclass<Foundation::NSCharacterSet::NSMutableCharacterSet> {}
+    class NSCharacterSet: ImmutableWithMutableSubclass<Foundation::NSCharacterSet::NSMutableCharacterSet> {
+        unsafe +whitespaceCharacterSet;
+        unsafe +whitespaceAndNewlineCharacterSet;
+        unsafe +alphanumericCharacterSet;
+        unsafe +decimalDigitCharacterSet;
+        unsafe +punctuationCharacterSet;
+        // The string is non-null, and its characters are copied
+        unsafe +characterSetWithCharactersInString:;
+    }
     class NSMutableCharacterSet: MutableWithImmutableSuperclass<Foundation::NSCharacterSet::NSCharacterSet> {}
 
     class NSOrderedSet: ImmutableWithMutableSubclass<Foundation::NSOrderedSet::NSMutableOrderedSet> {}
@@ -239,6 +274,10 @@ data! {
 
     class NSDecimalNumber: Immutable {}
 
+    class NSNull {
+        unsafe +null;
+    }
+
     class NSURLRequest: ImmutableWithMutableSubclass<Foundation::NSURLRequest::NSMutableURLRequest> {}
     class NSMutableURLRequest: MutableWithImmutableSuperclass<Foundation::NSURLRequest::NSURLRequest> {}
 
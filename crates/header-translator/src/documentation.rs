@@ -0,0 +1,168 @@
+use std::fmt;
+
+use clang::Entity;
+
+use crate::display_helper::FormatterFn;
+
+/// The comment attached to an entity in the header, re-emitted as Rust doc
+/// comments.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Documentation {
+    lines: Vec<String>,
+}
+
+impl Documentation {
+    pub fn parse(entity: &Entity<'_>) -> Self {
+        entity
+            .get_comment()
+            .map(|comment| Self::from_raw_comment(&comment))
+            .unwrap_or_default()
+    }
+
+    fn from_raw_comment(comment: &str) -> Self {
+        let raw_lines: Vec<&str> = comment.lines().collect();
+
+        let mut lines: Vec<String> = if raw_lines
+            .iter()
+            .all(|line| strip_line_comment(line.trim_start()).is_some())
+        {
+            // `///` comments; the lines share their indentation.
+            let lines = raw_lines
+                .iter()
+                .map(|line| strip_line_comment(line.trim_start()).unwrap())
+                .collect();
+            dedent(lines)
+        } else {
+            // `/**` comments; the first line starts after the comment marker,
+            // so it is dedented separately from the rest.
+            let mut lines = raw_lines.iter().enumerate().map(|(i, line)| {
+                let line = line.trim_end();
+                let line = line.strip_suffix("*/").unwrap_or(line);
+                if i == 0 {
+                    let line = line.trim_start();
+                    line.strip_prefix("/**<")
+                        .or_else(|| line.strip_prefix("/*!<"))
+                        .or_else(|| line.strip_prefix("/**"))
+                        .or_else(|| line.strip_prefix("/*!"))
+                        .unwrap_or(line)
+                } else {
+                    // Leading `*` in block comments
+                    line.trim_start().strip_prefix('*').unwrap_or(line)
+                }
+            });
+            let first = lines.next().unwrap_or("").trim().to_string();
+            let mut rest = dedent(lines.collect());
+            rest.insert(0, first);
+            rest
+        };
+
+        // Remove surrounding empty lines
+        while lines.first().map(|line| line.is_empty()).unwrap_or(false) {
+            lines.remove(0);
+        }
+        while lines.last().map(|line| line.is_empty()).unwrap_or(false) {
+            lines.pop();
+        }
+
+        Self { lines }
+    }
+
+    /// The documentation, with each line indented by four spaces, for items
+    /// inside `impl` and `enum` blocks.
+    pub fn indented(&self) -> impl fmt::Display + '_ {
+        FormatterFn(move |f| {
+            for line in &self.lines {
+                if line.is_empty() {
+                    writeln!(f, "    ///")?;
+                } else {
+                    writeln!(f, "    /// {line}")?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+fn strip_line_comment(line: &str) -> Option<&str> {
+    line.strip_prefix("///<")
+        .or_else(|| line.strip_prefix("///"))
+        .or_else(|| line.strip_prefix("//!<"))
+        .or_else(|| line.strip_prefix("//!"))
+}
+
+/// Remove the indentation that all the non-empty lines have in common, while
+/// keeping the relative indentation (e.g. in code blocks).
+fn dedent(lines: Vec<&str>) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .into_iter()
+        .map(|line| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                let line = line.get(indent..).unwrap_or_else(|| line.trim_start());
+                line.trim_end().to_string()
+            }
+        })
+        .collect()
+}
+
+impl fmt::Display for Documentation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            if line.is_empty() {
+                writeln!(f, "///")?;
+            } else {
+                writeln!(f, "/// {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_raw_comment() {
+        fn check(inp: &str, expected: &[&str]) {
+            let actual = Documentation::from_raw_comment(inp);
+            assert_eq!(actual.lines, expected);
+        }
+
+        check("", &[]);
+        check("/// Foo", &["Foo"]);
+        check("/// Foo\n/// Bar", &["Foo", "Bar"]);
+        check("/** Foo */", &["Foo"]);
+        check("/**< Foo */", &["Foo"]);
+        check("///< Foo", &["Foo"]);
+        check("/**\n * Foo\n *\n * Bar\n */", &["Foo", "", "Bar"]);
+        check("/*! Foo\n    bar\n*/", &["Foo", "bar"]);
+        // Indentation in code blocks is kept
+        check(
+            "/// Foo\n/// ```\n/// if x {\n///     y\n/// }\n/// ```",
+            &["Foo", "```", "if x {", "    y", "}", "```"],
+        );
+        check(
+            "/**\n * Foo\n *\n *     if x {\n *         y\n *     }\n */",
+            &["Foo", "", "    if x {", "        y", "    }"],
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let doc = Documentation::from_raw_comment("/** Foo\n\n   bar */");
+        assert_eq!(doc.to_string(), "/// Foo\n///\n/// bar\n");
+        assert_eq!(
+            doc.indented().to_string(),
+            "    /// Foo\n    ///\n    /// bar\n"
+        );
+        assert_eq!(Documentation::default().to_string(), "");
+    }
+}
@@ -10,17 +10,35 @@ use crate::stmt::Stmt;
 use crate::Library;
 
 pub fn global_analysis(library: &mut Library) {
+    let merge = library.data.merge_categories;
+    let swift_names = library.data.swift_names;
     for (name, file) in &mut library.files {
         let _span = debug_span!("file", name).entered();
-        update_file(file);
+        update_file(file, merge, swift_names);
     }
 }
 
-fn update_file(file: &mut File) {
+fn update_file(file: &mut File, merge: bool, swift_names: bool) {
+    // These must happen before disambiguation, such that clashes between
+    // the new names, and between the class and the categories, are
+    // resolved.
+    if swift_names {
+        use_swift_names(&mut file.stmts);
+    }
+    if merge {
+        merge_categories(&mut file.stmts);
+    }
+
+    disambiguate_names(&mut file.stmts);
+
+    fix_typedef_enums(file);
+}
+
+fn disambiguate_names(stmts: &mut [Stmt]) {
     // disambiguate duplicate names
     // NOTE: this only works within single files
     let mut names = BTreeMap::<(String, String), &mut Method>::new();
-    for stmt in file.stmts.iter_mut() {
+    for stmt in stmts.iter_mut() {
         match stmt {
             Stmt::ExternMethods {
                 cls: id, methods, ..
@@ -57,8 +75,83 @@ fn update_file(file: &mut File) {
             _ => {}
         }
     }
+}
+
+fn use_swift_names(stmts: &mut [Stmt]) {
+    for stmt in stmts.iter_mut() {
+        match stmt {
+            Stmt::ExternMethods { methods, .. }
+            | Stmt::ExternCategory { methods, .. }
+            | Stmt::ProtocolDecl { methods, .. } => {
+                for method in methods.iter_mut() {
+                    method.use_swift_name();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Fold `extern_methods!` blocks from categories into the primary
+/// `extern_methods!` block of the class.
+///
+/// Only categories declared after the class in the same file, and with the
+/// same availability and requirements, are merged.
+fn merge_categories(stmts: &mut Vec<Stmt>) {
+    // Index of the primary `extern_methods!` block for each class.
+    let mut primary = BTreeMap::<String, usize>::new();
+    for stmt in mem::take(stmts) {
+        if let Stmt::ExternMethods {
+            cls,
+            source_superclass: None,
+            category_name,
+            ..
+        } = &stmt
+        {
+            if category_name.is_none() {
+                primary.insert(cls.name.clone(), stmts.len());
+            } else if let Some(&i) = primary.get(&cls.name) {
+                let unmerged = try_merge_category(&mut stmts[i], stmt);
+                stmts.extend(unmerged);
+                continue;
+            }
+        }
+        stmts.push(stmt);
+    }
+}
+
+/// Move the methods of `category` into `primary`, or return the category if
+/// the two can't be merged.
+fn try_merge_category(primary: &mut Stmt, category: Stmt) -> Option<Stmt> {
+    match (primary, category) {
+        (
+            Stmt::ExternMethods {
+                availability,
+                cls_required_items,
+                cls_generics,
+                methods,
+                ..
+            },
+            Stmt::ExternMethods {
+                availability: category_availability,
+                cls_required_items: category_required_items,
+                cls_generics: category_generics,
+                methods: category_methods,
+                ..
+            },
+        ) if *availability == category_availability
+            && *cls_required_items == category_required_items
+            && *cls_generics == category_generics =>
+        {
+            methods.extend(category_methods);
+            None
+        }
+        (_, category) => Some(category),
+    }
+}
 
-    // Fix up a few typedef + enum declarations
+/// Fix up a few typedef + enum declarations.
+fn fix_typedef_enums(file: &mut File) {
     let mut iter = mem::take(&mut file.stmts).into_iter().peekable();
     while let Some(stmt) = iter.next() {
         if let Stmt::AliasDecl {
@@ -88,3 +181,78 @@ fn update_file(file: &mut File) {
         file.stmts.push(stmt);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::availability::Availability;
+    use crate::ItemIdentifier;
+
+    fn extern_methods(category_name: Option<&str>, selectors: &[&str]) -> Stmt {
+        let cls = ItemIdentifier::from_raw("MyClass".to_string(), "MyLib".into(), "MyClass".into());
+        Stmt::ExternMethods {
+            location: cls.location().clone(),
+            availability: Availability::default(),
+            cls,
+            cls_required_items: vec![],
+            source_superclass: None,
+            cls_generics: vec![],
+            category_name: category_name.map(|name| name.to_string()),
+            methods: selectors.iter().map(|sel| Method::dummy(sel)).collect(),
+            default_id_from_init: false,
+        }
+    }
+
+    fn fn_names(stmt: &Stmt) -> Vec<&str> {
+        match stmt {
+            Stmt::ExternMethods { methods, .. } => {
+                methods.iter().map(|method| &*method.fn_name).collect()
+            }
+            _ => panic!("expected extern_methods!"),
+        }
+    }
+
+    #[test]
+    fn test_merge_categories() {
+        let mut stmts = vec![
+            extern_methods(None, &["foo", "bar"]),
+            extern_methods(Some("MyCategory"), &["baz", "bar:"]),
+        ];
+        merge_categories(&mut stmts);
+        disambiguate_names(&mut stmts);
+
+        assert_eq!(stmts.len(), 1);
+        assert_eq!(fn_names(&stmts[0]), ["foo", "bar", "baz", "bar_"]);
+    }
+
+    #[test]
+    fn test_swift_names() {
+        let mut stmts = vec![extern_methods(None, &["fooWithBar:", "baz", "qux:", "qux"])];
+        let Stmt::ExternMethods { methods, .. } = &mut stmts[0] else {
+            unreachable!()
+        };
+        methods[0].swift_name = Some("foo(bar:)".to_string());
+        methods[1].swift_name = Some("getter:Thing.baz()".to_string());
+        methods[2].swift_name = Some("quxWithURL(_:)".to_string());
+        methods[3].swift_name = Some("quxWithURL()".to_string());
+
+        use_swift_names(&mut stmts);
+        disambiguate_names(&mut stmts);
+
+        // The clashing names fall back to using the selector.
+        assert_eq!(fn_names(&stmts[0]), ["foo_bar", "baz", "qux_", "qux"]);
+    }
+
+    #[test]
+    fn test_merge_categories_only_after_class() {
+        let mut stmts = vec![
+            extern_methods(Some("MyCategory"), &["baz"]),
+            extern_methods(None, &["foo"]),
+        ];
+        merge_categories(&mut stmts);
+
+        assert_eq!(stmts.len(), 2);
+        assert_eq!(fn_names(&stmts[0]), ["baz"]);
+        assert_eq!(fn_names(&stmts[1]), ["foo"]);
+    }
+}
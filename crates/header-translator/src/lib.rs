@@ -15,6 +15,7 @@ mod config;
 mod context;
 mod data;
 mod display_helper;
+mod documentation;
 mod expr;
 mod file;
 mod global_analysis;
@@ -344,8 +344,10 @@ fn get_translation_unit<'i: 'tu, 'tu>(
             "-fobjc-arc",
             "-fobjc-arc-exceptions",
             "-fobjc-abi-version=2", // 3??
-            // "-fparse-all-comments",
-            // TODO: "-fretain-comments-from-system-headers"
+            // The SDK headers are system headers. Only doc comments are
+            // parsed, since regular comments are often implementation notes
+            // (or commented-out code) that should not end up in the docs.
+            "-fretain-comments-from-system-headers",
             "-fapinotes",
             "-isysroot",
             sdk.path.to_str().unwrap(),
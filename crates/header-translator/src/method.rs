@@ -1,6 +1,7 @@
 use std::fmt;
 
 use clang::{Entity, EntityKind, ObjCAttributes, ObjCQualifiers};
+use heck::ToSnakeCase;
 
 use crate::availability::Availability;
 use crate::config::MethodData;
@@ -238,11 +239,26 @@ impl MemoryManagement {
     }
 }
 
+/// The kind of an `init` method, if the class declares any designated
+/// initializers.
+///
+/// <https://developer.apple.com/library/archive/documentation/General/Conceptual/DevPedia-CocoaCore/MultipleInitializers.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Initializer {
+    /// Marked with `NS_DESIGNATED_INITIALIZER`.
+    Designated,
+    /// Any other initializer on a class that has designated initializers;
+    /// these must delegate to another initializer on the same class.
+    Convenience,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Method {
     pub selector: String,
     pub fn_name: String,
+    /// The name from `NS_SWIFT_NAME(...)`, if any.
+    pub swift_name: Option<String>,
     pub availability: Availability,
     pub is_class: bool,
     is_optional: bool,
@@ -253,6 +269,7 @@ pub struct Method {
     safe: bool,
     mutating: bool,
     is_pub: bool,
+    initializer: Option<Initializer>,
     // Thread-safe, even on main-thread only (@MainActor/@UIActor) classes
     non_isolated: bool,
     mainthreadonly: bool,
@@ -323,10 +340,24 @@ impl Method {
         self.selector == "new"
             && self.is_class
             && self.arguments.is_empty()
+            && self.result_type.is_nonnull_instancetype()
             && self.safe
             && !self.mainthreadonly
     }
 
+    /// Whether `DefaultId` can be implemented with `alloc` followed by this
+    /// method, for classes that don't have a usable `new`.
+    ///
+    /// The class itself must not be main thread only, since `alloc` requires
+    /// that.
+    pub(crate) fn usable_init_in_default_id(&self) -> bool {
+        self.selector == "init"
+            && !self.is_class
+            && self.arguments.is_empty()
+            && self.result_type.is_nonnull_instancetype()
+            && self.safe
+    }
+
     /// Takes `EntityKind::ObjCPropertyDecl`.
     pub(crate) fn partial_property(entity: Entity<'_>) -> PartialProperty<'_> {
         let attributes = entity.get_objc_attributes();
@@ -356,7 +387,7 @@ impl Method {
         parent_is_mainthreadonly: bool,
         is_pub: bool,
         context: &Context<'_>,
-    ) -> Option<(bool, Method)> {
+    ) -> Option<Method> {
         let selector = entity.get_name().expect("method selector");
         let _span = debug_span!("method", selector).entered();
 
@@ -481,6 +512,15 @@ impl Method {
 
         let fn_name = selector.trim_end_matches(|c| c == ':').replace(':', "_");
 
+        let mut swift_name = None;
+        immediate_children(&entity, |entity, _span| {
+            if entity.get_kind() == EntityKind::UnexposedAttr {
+                if let Some(name) = UnexposedAttr::parse_swift_name(&entity, context) {
+                    swift_name = Some(name);
+                }
+            }
+        });
+
         let mainthreadonly = mainthreadonly_override(
             &result_type,
             arguments.iter().map(|(_, ty)| ty),
@@ -489,28 +529,29 @@ impl Method {
             modifiers.mainthreadonly,
         );
 
-        Some((
-            modifiers.designated_initializer,
-            Method {
-                selector,
-                fn_name,
-                availability,
-                is_class,
-                is_optional: entity.is_objc_optional(),
-                memory_management,
-                arguments,
-                result_type,
-                is_error,
-                safe: !data.unsafe_,
-                // Mutable if the parent is mutable is a reasonable default,
-                // since immutable methods are usually either declared on an
-                // immutable subclass, or as a property.
-                mutating: data.mutating.unwrap_or(parent_is_mutable),
-                is_pub,
-                non_isolated: modifiers.non_isolated,
-                mainthreadonly,
-            },
-        ))
+        Some(Method {
+            selector,
+            fn_name,
+            swift_name,
+            availability,
+            is_class,
+            is_optional: entity.is_objc_optional(),
+            memory_management,
+            arguments,
+            result_type,
+            is_error,
+            safe: !data.unsafe_,
+            // Mutable if the parent is mutable is a reasonable default,
+            // since immutable methods are usually either declared on an
+            // immutable subclass, or as a property.
+            mutating: data.mutating.unwrap_or(parent_is_mutable),
+            is_pub,
+            initializer: modifiers
+                .designated_initializer
+                .then_some(Initializer::Designated),
+            non_isolated: modifiers.non_isolated,
+            mainthreadonly,
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -571,6 +612,7 @@ impl Method {
             Some(Method {
                 selector: getter_sel.clone(),
                 fn_name: getter_sel,
+                swift_name: None,
                 availability: availability.clone(),
                 is_class,
                 is_optional: entity.is_objc_optional(),
@@ -583,6 +625,7 @@ impl Method {
                 // is, so let's default to immutable.
                 mutating: getter_data.mutating.unwrap_or(false),
                 is_pub,
+                initializer: None,
                 non_isolated: modifiers.non_isolated,
                 mainthreadonly,
             })
@@ -616,6 +659,7 @@ impl Method {
                 Some(Method {
                     selector,
                     fn_name,
+                    swift_name: None,
                     availability,
                     is_class,
                     is_optional: entity.is_objc_optional(),
@@ -627,6 +671,7 @@ impl Method {
                     // Setters are usually mutable if the class itself is.
                     mutating: setter_data.mutating.unwrap_or(parent_is_mutable),
                     is_pub,
+                    initializer: None,
                     non_isolated: modifiers.non_isolated,
                     mainthreadonly,
                 })
@@ -640,6 +685,32 @@ impl Method {
         (getter, setter)
     }
 
+    pub(crate) fn is_designated_initializer(&self) -> bool {
+        self.initializer == Some(Initializer::Designated)
+    }
+
+    /// Mark the method as a convenience initializer, if it is an `init`
+    /// method that is not a designated initializer.
+    pub(crate) fn mark_convenience_initializer(&mut self) {
+        if self.memory_management == MemoryManagement::IdInit && self.initializer.is_none() {
+            self.initializer = Some(Initializer::Convenience);
+        }
+    }
+
+    /// Forget the designated/convenience distinction, used when emitting
+    /// the method on a subclass where it may no longer hold.
+    pub(crate) fn clear_initializer(&mut self) {
+        self.initializer = None;
+    }
+
+    /// Use the name from `NS_SWIFT_NAME(...)` as the name of the Rust
+    /// function, if the method has one.
+    pub(crate) fn use_swift_name(&mut self) {
+        if let Some(fn_name) = self.swift_name.as_deref().and_then(swift_name_to_fn_name) {
+            self.fn_name = fn_name;
+        }
+    }
+
     pub(crate) fn emit_on_subclasses(&self) -> bool {
         if !self.result_type.is_instancetype() {
             return false;
@@ -664,6 +735,79 @@ impl Method {
     }
 }
 
+/// Convert a Swift method name to snake case.
+///
+/// The argument labels are appended to the base name, except for `_`, so
+/// e.g. `replaceCharacters(in:with:)` becomes `replace_characters_in_with`.
+///
+/// Returns `None` if the name is not a plain method name, such as when the
+/// method is imported as a property with `getter:`.
+fn swift_name_to_fn_name(swift_name: &str) -> Option<String> {
+    fn is_ident(s: &str) -> bool {
+        !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    let (base, labels) = match swift_name.split_once('(') {
+        Some((base, labels)) => (base, labels.strip_suffix(')')?),
+        None => (swift_name, ""),
+    };
+    if !is_ident(base) {
+        return None;
+    }
+
+    let mut fn_name = base.to_snake_case();
+    for label in labels.split_terminator(':') {
+        if !is_ident(label) {
+            return None;
+        }
+        if label != "_" {
+            fn_name.push('_');
+            fn_name.push_str(&label.to_snake_case());
+        }
+    }
+    Some(fn_name)
+}
+
+#[cfg(test)]
+impl Method {
+    /// A simple instance method that takes no arguments and returns nothing.
+    pub(crate) fn dummy(selector: &str) -> Self {
+        Self {
+            selector: selector.to_string(),
+            fn_name: selector.trim_end_matches(':').replace(':', "_"),
+            swift_name: None,
+            availability: Availability::default(),
+            is_class: false,
+            is_optional: false,
+            memory_management: MemoryManagement::Normal,
+            arguments: vec![],
+            result_type: Ty::VOID_RESULT,
+            is_error: false,
+            safe: true,
+            mutating: false,
+            is_pub: true,
+            initializer: None,
+            non_isolated: false,
+            mainthreadonly: false,
+        }
+    }
+
+    /// A class method that takes no arguments and returns a non-null
+    /// `instancetype`, like `new`.
+    pub(crate) fn dummy_class_instancetype(selector: &str) -> Self {
+        Self {
+            is_class: true,
+            result_type: Ty::Pointer {
+                nullability: clang::Nullability::NonNull,
+                is_const: false,
+                lifetime: crate::rust_type::Lifetime::Unspecified,
+                pointee: Box::new(Ty::Self_),
+            },
+            ..Self::dummy(selector)
+        }
+    }
+}
+
 impl fmt::Display for Method {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let _span = debug_span!("method", self.fn_name).entered();
@@ -677,7 +821,25 @@ impl fmt::Display for Method {
         // Attributes
         //
 
+        match self.initializer {
+            Some(Initializer::Designated) => {
+                writeln!(f, "        /// This is a designated initializer.")?;
+            }
+            Some(Initializer::Convenience) => {
+                writeln!(
+                    f,
+                    "        /// This is a convenience initializer, the designated"
+                )?;
+                writeln!(
+                    f,
+                    "        /// initializers of this class are marked as such."
+                )?;
+            }
+            None => {}
+        }
+
         write!(f, "{}", self.availability)?;
+        write!(f, "{}", self.availability.cfg_gate_ln())?;
 
         if self.is_optional {
             writeln!(f, "        #[optional]")?;
@@ -759,3 +921,74 @@ pub(crate) fn handle_reserved(name: &str) -> String {
         name.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clang::Nullability;
+
+    use super::*;
+    use crate::rust_type::Lifetime;
+
+    #[test]
+    fn test_swift_name_to_fn_name() {
+        #[track_caller]
+        fn check(swift_name: &str, expected: Option<&str>) {
+            assert_eq!(swift_name_to_fn_name(swift_name).as_deref(), expected);
+        }
+
+        check("foo(bar:)", Some("foo_bar"));
+        check("count", Some("count"));
+        check("removeAll()", Some("remove_all"));
+        check("append(_:)", Some("append"));
+        check(
+            "replaceCharacters(in:with:)",
+            Some("replace_characters_in_with"),
+        );
+        check("init(contentsOfURL:)", Some("init_contents_of_url"));
+        check("getter:foo()", None);
+        check("Foo.bar(_:)", None);
+        check("foo(bar:", None);
+    }
+
+    #[test]
+    fn test_usable_in_default_id() {
+        fn instancetype(nullability: Nullability) -> Ty {
+            Ty::Pointer {
+                nullability,
+                is_const: false,
+                lifetime: Lifetime::Unspecified,
+                pointee: Box::new(Ty::Self_),
+            }
+        }
+
+        let mut new = Method::dummy("new");
+        new.is_class = true;
+        new.result_type = instancetype(Nullability::NonNull);
+        assert!(new.usable_in_default_id());
+        assert!(!new.usable_init_in_default_id());
+
+        let mut init = Method::dummy("init");
+        init.result_type = instancetype(Nullability::NonNull);
+        assert!(init.usable_init_in_default_id());
+        assert!(!init.usable_in_default_id());
+
+        // Nullable return types cannot be used
+        let mut nullable_init = init.clone();
+        nullable_init.result_type = instancetype(Nullability::Nullable);
+        assert!(!nullable_init.usable_init_in_default_id());
+
+        // Neither can unsafe or main thread only methods
+        let mut unsafe_new = new.clone();
+        unsafe_new.safe = false;
+        assert!(!unsafe_new.usable_in_default_id());
+        let mut mainthreadonly_new = new.clone();
+        mainthreadonly_new.mainthreadonly = true;
+        assert!(!mainthreadonly_new.usable_in_default_id());
+
+        // Or methods that aren't `new` or `init`
+        let mut other = Method::dummy("newObject");
+        other.is_class = true;
+        other.result_type = instancetype(Nullability::NonNull);
+        assert!(!other.usable_in_default_id());
+    }
+}
@@ -1845,6 +1845,17 @@ impl Ty {
         matches!(self, Self::Pointer { pointee, .. } if **pointee == Self::Self_)
     }
 
+    pub(crate) fn is_nonnull_instancetype(&self) -> bool {
+        matches!(
+            self,
+            Self::Pointer {
+                nullability: Nullability::NonNull,
+                pointee,
+                ..
+            } if **pointee == Self::Self_
+        )
+    }
+
     pub(crate) fn is_typedef_to(&self, s: &str) -> bool {
         matches!(self, Self::TypeDef { id, .. } if id.name == s)
     }
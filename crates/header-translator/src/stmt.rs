@@ -12,6 +12,7 @@ use crate::availability::Availability;
 use crate::config::{ClassData, MethodData};
 use crate::context::Context;
 use crate::display_helper::FormatterFn;
+use crate::documentation::Documentation;
 use crate::expr::Expr;
 use crate::id::cfg_gate_ln;
 use crate::id::ItemIdentifier;
@@ -41,6 +42,77 @@ impl fmt::Display for Derives {
     }
 }
 
+/// Conversions between a class and the CoreFoundation type that it is
+/// toll-free bridged with.
+struct TollFreeBridge<'a, G> {
+    cls: &'a str,
+    cf: &'a str,
+    cfg_gate: G,
+}
+
+impl<G: fmt::Display> fmt::Display for TollFreeBridge<'_, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { cls, cf, cfg_gate } = self;
+        let cf_name = cf.rsplit("::").next().unwrap_or(cf);
+
+        writeln!(f)?;
+        write!(f, "{cfg_gate}")?;
+        writeln!(f, "impl {cls} {{")?;
+        writeln!(
+            f,
+            "    /// Get a reference to the toll-free bridged `{cf_name}`."
+        )?;
+        writeln!(f, "    #[inline]")?;
+        writeln!(f, "    pub fn as_cf(&self) -> &{cf} {{")?;
+        writeln!(
+            f,
+            "        // SAFETY: `{cls}` is toll-free bridged with `{cf_name}`."
+        )?;
+        writeln!(
+            f,
+            "        unsafe {{ &*(self as *const Self).cast::<{cf}>() }}"
+        )?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+
+        writeln!(f)?;
+        write!(f, "{cfg_gate}")?;
+        writeln!(f, "impl AsRef<{cf}> for {cls} {{")?;
+        writeln!(f, "    #[inline]")?;
+        writeln!(f, "    fn as_ref(&self) -> &{cf} {{")?;
+        writeln!(f, "        self.as_cf()")?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+
+        writeln!(f)?;
+        write!(f, "{cfg_gate}")?;
+        writeln!(f, "impl<'a> From<&'a {cls}> for &'a {cf} {{")?;
+        writeln!(f, "    #[inline]")?;
+        writeln!(f, "    fn from(obj: &'a {cls}) -> Self {{")?;
+        writeln!(f, "        obj.as_cf()")?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+
+        writeln!(f)?;
+        write!(f, "{cfg_gate}")?;
+        writeln!(f, "impl<'a> From<&'a {cf}> for &'a {cls} {{")?;
+        writeln!(f, "    #[inline]")?;
+        writeln!(f, "    fn from(cf: &'a {cf}) -> Self {{")?;
+        writeln!(
+            f,
+            "        // SAFETY: `{cf_name}` is toll-free bridged with `{cls}`."
+        )?;
+        writeln!(
+            f,
+            "        unsafe {{ &*(cf as *const {cf}).cast::<{cls}>() }}"
+        )?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
 /// Find all protocols, protocol's protocols and superclass' protocols.
 fn parse_protocols<'tu>(
     entity: &Entity<'tu>,
@@ -214,6 +286,11 @@ pub(crate) fn method_or_property_entities<'tu>(
     entities
 }
 
+/// Parse the methods and properties in a declaration.
+///
+/// Returns the methods to emit, the selectors of the designated
+/// initializers, and the ids of methods that were declared but are
+/// unavailable on all platforms (and so are not emitted).
 fn parse_methods(
     entity: &Entity<'_>,
     get_data: impl Fn(&str) -> MethodData,
@@ -221,9 +298,10 @@ fn parse_methods(
     thread_safety: &ThreadSafety,
     is_pub: bool,
     context: &Context<'_>,
-) -> (Vec<Method>, Vec<String>) {
+) -> (Vec<Method>, Vec<String>, Vec<(bool, String)>) {
     let mut methods = Vec::new();
     let mut designated_initializers = Vec::new();
+    let mut unavailable = Vec::new();
 
     for entity in method_or_property_entities(entity, &get_data) {
         match entity.get_kind() {
@@ -232,7 +310,7 @@ fn parse_methods(
 
                 let data = get_data(&selector);
 
-                if let Some((designated_initializer, method)) = Method::parse_method(
+                if let Some(method) = Method::parse_method(
                     entity,
                     data,
                     is_mutable,
@@ -240,7 +318,9 @@ fn parse_methods(
                     is_pub,
                     context,
                 ) {
-                    if designated_initializer {
+                    if method.is_designated_initializer()
+                        && !method.availability.is_unavailable_everywhere()
+                    {
                         designated_initializers.push(method.selector.clone());
                     }
                     methods.push(method);
@@ -278,7 +358,49 @@ fn parse_methods(
         }
     }
 
-    (methods, designated_initializers)
+    // Methods that are unavailable everywhere are not emitted, but we still
+    // keep track of them, so that they are not re-emitted from a superclass.
+    methods.retain(|method| {
+        if method.availability.is_unavailable_everywhere() {
+            debug!(
+                method.selector,
+                "skipping method unavailable on all platforms"
+            );
+            unavailable.push(method.id());
+            false
+        } else {
+            true
+        }
+    });
+
+    // If a class declares any designated initializers, all its other
+    // initializers are convenience initializers.
+    if !designated_initializers.is_empty() {
+        for method in &mut methods {
+            method.mark_convenience_initializer();
+        }
+    }
+
+    (methods, designated_initializers, unavailable)
+}
+
+/// Only keep the methods from a superclass that should be emitted on the
+/// subclass, i.e. those that haven't already been seen on the subclass (or on
+/// a closer superclass).
+///
+/// The kept methods and the methods that the superclass made unavailable are
+/// added to `seen_methods`.
+fn retain_superclass_methods(
+    methods: &mut Vec<Method>,
+    unavailable_methods: Vec<(bool, String)>,
+    seen_methods: &mut BTreeSet<(bool, String)>,
+) {
+    methods.retain(|method| method.emit_on_subclasses() && !seen_methods.contains(&method.id()));
+    for method in methods.iter_mut() {
+        method.clear_initializer();
+    }
+    seen_methods.extend(methods.iter().map(|method| method.id()));
+    seen_methods.extend(unavailable_methods);
 }
 
 /// Get the items required for a given interface or protocol declaration to be
@@ -437,6 +559,7 @@ pub enum Stmt {
         mutability: Mutability,
         skipped: bool,
         sendable: bool,
+        bridged_to: Option<String>,
     },
     /// @interface class_name (category_name) <protocols*>
     /// ->
@@ -450,6 +573,9 @@ pub enum Stmt {
         cls_generics: Vec<String>,
         category_name: Option<String>,
         methods: Vec<Method>,
+        /// Implement `DefaultId` using `alloc` and `init`, since the class
+        /// has no usable `new` method.
+        default_id_from_init: bool,
     },
     /// @interface class_name (category_name) <protocols*>
     /// ->
@@ -527,7 +653,7 @@ pub enum Stmt {
         availability: Availability,
         ty: Ty,
         kind: Option<UnexposedAttr>,
-        variants: Vec<(String, Availability, Expr)>,
+        variants: Vec<(String, Documentation, Availability, Expr)>,
         sendable: Option<bool>,
     },
     /// Anonymous enum variants are emitted as free constants.
@@ -537,6 +663,7 @@ pub enum Stmt {
     /// };
     ConstDecl {
         id: ItemIdentifier,
+        documentation: Documentation,
         availability: Availability,
         ty: Ty,
         value: Expr,
@@ -645,7 +772,7 @@ impl Stmt {
 
                 verify_objc_decl(entity, context);
                 let generics = parse_class_generics(entity, context);
-                let (methods, designated_initializers) = parse_methods(
+                let (methods, designated_initializers, unavailable_methods) = parse_methods(
                     entity,
                     |name| ClassData::get_method_data(data, name),
                     data.map(|data| data.mutability.is_mutable())
@@ -671,8 +798,14 @@ impl Stmt {
 
                 // Used for duplicate checking (sometimes the subclass
                 // defines the same method that the superclass did).
-                let mut seen_methods: BTreeSet<_> =
-                    methods.iter().map(|method| method.id()).collect();
+                //
+                // Methods that the subclass has made unavailable are included
+                // here, to avoid making them available again.
+                let mut seen_methods: BTreeSet<_> = methods
+                    .iter()
+                    .map(|method| method.id())
+                    .chain(unavailable_methods)
+                    .collect();
 
                 let superclass_methods: Vec<_> = superclasses_full
                     .iter()
@@ -685,7 +818,7 @@ impl Stmt {
                         // Explicitly keep going, even if the class itself is skipped
                         // if superclass_data.skipped
 
-                        let (mut methods, _) = parse_methods(
+                        let (mut methods, _, unavailable_methods) = parse_methods(
                             entity,
                             |name| {
                                 let data = ClassData::get_method_data(data, name);
@@ -700,10 +833,11 @@ impl Stmt {
                             true,
                             context,
                         );
-                        methods.retain(|method| {
-                            method.emit_on_subclasses() && !seen_methods.contains(&method.id())
-                        });
-                        seen_methods.extend(methods.iter().map(|method| method.id()));
+                        retain_superclass_methods(
+                            &mut methods,
+                            unavailable_methods,
+                            &mut seen_methods,
+                        );
                         if methods.is_empty() {
                             None
                         } else {
@@ -716,11 +850,27 @@ impl Stmt {
                                 cls_generics: generics.clone(),
                                 category_name: None,
                                 methods,
+                                default_id_from_init: false,
                             })
                         }
                     })
                     .collect();
 
+                // `new` is usually inherited from `NSObject`, but if it has
+                // been made unavailable, we can still use `init`.
+                let has_default_id_new = methods
+                    .iter()
+                    .chain(superclass_methods.iter().flat_map(|stmt| match stmt {
+                        Self::ExternMethods { methods, .. } => &**methods,
+                        _ => &[],
+                    }))
+                    .any(|method| method.usable_in_default_id());
+                let default_id_from_init = !has_default_id_new
+                    && !thread_safety.inferred_mainthreadonly()
+                    && methods
+                        .iter()
+                        .any(|method| method.usable_init_in_default_id());
+
                 let methods = Self::ExternMethods {
                     location: id.location().clone(),
                     availability: availability.clone(),
@@ -730,6 +880,7 @@ impl Stmt {
                     cls_generics: generics.clone(),
                     category_name: None,
                     methods,
+                    default_id_from_init,
                 };
 
                 iter::once(Self::ClassDecl {
@@ -749,6 +900,7 @@ impl Stmt {
                     // Ignore sendability on superclasses; since it's an auto
                     // trait, it's propagated to subclasses anyhow!
                     sendable: thread_safety.explicit_sendable(),
+                    bridged_to: data.and_then(|data| data.bridged_to.clone()),
                 })
                 .chain(protocols.into_iter().map(|(p, entity)| Self::ProtocolImpl {
                     location: id.location().clone(),
@@ -833,7 +985,7 @@ impl Stmt {
                 if cls.library() == category.library() {
                     // extern_methods!
 
-                    let (methods, designated_initializers) = parse_methods(
+                    let (methods, designated_initializers, _) = parse_methods(
                         entity,
                         |name| ClassData::get_method_data(data, name),
                         data.map(|data| data.mutability.is_mutable())
@@ -857,7 +1009,7 @@ impl Stmt {
                             context.library(&subclass).class_data.get(&subclass.name);
                         assert!(!subclass_data.map(|data| data.skipped).unwrap_or_default());
 
-                        let (mut methods, _) = parse_methods(
+                        let (mut methods, _, _) = parse_methods(
                             entity,
                             |name| {
                                 let data = ClassData::get_method_data(data, name);
@@ -872,6 +1024,9 @@ impl Stmt {
                             context,
                         );
                         methods.retain(|method| method.emit_on_subclasses());
+                        for method in &mut methods {
+                            method.clear_initializer();
+                        }
                         if methods.is_empty() {
                             None
                         } else {
@@ -888,6 +1043,7 @@ impl Stmt {
                                 cls_generics: generics.clone(),
                                 category_name: category.name.clone(),
                                 methods,
+                                default_id_from_init: false,
                             })
                         }
                     } else {
@@ -903,6 +1059,7 @@ impl Stmt {
                         cls_generics: generics.clone(),
                         category_name: category.name.clone(),
                         methods,
+                        default_id_from_init: false,
                     })
                     .chain(extra_methods)
                     .chain(protocol_impls)
@@ -932,7 +1089,7 @@ impl Stmt {
                         }
                     });
 
-                    let (methods, designated_initializers) = parse_methods(
+                    let (methods, designated_initializers, _) = parse_methods(
                         entity,
                         |name| ClassData::get_method_data(data, name),
                         false,
@@ -1003,7 +1160,7 @@ impl Stmt {
                     .map(|protocol| ItemIdentifier::new(&protocol, context))
                     .map(|protocol| context.replace_protocol_name(protocol))
                     .collect();
-                let (methods, designated_initializers) = parse_methods(
+                let (methods, designated_initializers, _) = parse_methods(
                     entity,
                     |name| {
                         data.and_then(|data| data.methods.get(name))
@@ -1188,6 +1345,7 @@ impl Stmt {
                 immediate_children(entity, |entity, _span| match entity.get_kind() {
                     EntityKind::EnumConstantDecl => {
                         let name = entity.get_name().expect("enum constant name");
+                        let documentation = Documentation::parse(&entity);
                         let availability = Availability::parse(&entity, context);
 
                         if data
@@ -1230,7 +1388,7 @@ impl Stmt {
                             });
                         };
 
-                        variants.push((name, availability, expr));
+                        variants.push((name, documentation, availability, expr));
                     }
                     EntityKind::UnexposedAttr => {
                         if let Some(attr) = UnexposedAttr::parse(&entity, context) {
@@ -1277,13 +1435,16 @@ impl Stmt {
                     variants
                         .into_iter()
                         .enumerate()
-                        .map(|(i, (name, availability, value))| Self::ConstDecl {
-                            id: id.clone().map_name(|_| name),
-                            availability,
-                            ty: ty.clone(),
-                            value,
-                            is_last: i == variants_len - 1,
-                        })
+                        .map(
+                            |(i, (name, documentation, availability, value))| Self::ConstDecl {
+                                id: id.clone().map_name(|_| name),
+                                documentation,
+                                availability,
+                                ty: ty.clone(),
+                                value,
+                                is_last: i == variants_len - 1,
+                            },
+                        )
                         .collect()
                 } else {
                     vec![Self::EnumDecl {
@@ -1547,7 +1708,7 @@ impl Stmt {
                 .collect(),
             Self::EnumDecl { variants, .. } => variants
                 .iter()
-                .flat_map(|(_, _, expr)| expr.required_items())
+                .flat_map(|(_, _, _, expr)| expr.required_items())
                 .collect(),
             _ => vec![],
         };
@@ -1654,6 +1815,7 @@ impl Stmt {
                     mutability,
                     skipped,
                     sendable,
+                    bridged_to,
                 } => {
                     if *skipped {
                         return Ok(());
@@ -1761,6 +1923,22 @@ impl Stmt {
                         write!(f, "{}", self.cfg_gate_ln(config))?;
                         writeln!(f, "unsafe impl Sync for {} {{}}", id.name)?;
                     }
+
+                    if let Some(bridged_to) = bridged_to {
+                        if generics.is_empty() {
+                            write!(
+                                f,
+                                "{}",
+                                TollFreeBridge {
+                                    cls: &id.name,
+                                    cf: bridged_to,
+                                    cfg_gate: self.cfg_gate_ln(config),
+                                }
+                            )?;
+                        } else {
+                            error!(?id, "cannot emit toll-free bridge for generic class");
+                        }
+                    }
                 }
                 Self::ExternMethods {
                     location: _,
@@ -1771,6 +1949,7 @@ impl Stmt {
                     cls_generics,
                     category_name,
                     methods,
+                    default_id_from_init,
                 } => {
                     writeln!(f, "extern_methods!(")?;
                     if let Some(source_superclass) = source_superclass {
@@ -1816,12 +1995,35 @@ impl Stmt {
                     writeln!(f, "    }}")?;
                     writeln!(f, ");")?;
 
-                    if let Some(method) =
+                    let default_id_skipped = config
+                        .library(cls)
+                        .class_data
+                        .get(&cls.name)
+                        .map(|data| data.default_id_skipped)
+                        .unwrap_or_default();
+
+                    let default_id = if default_id_skipped {
+                        None
+                    } else if let Some(method) =
                         methods.iter().find(|method| method.usable_in_default_id())
                     {
+                        Some((method, format!("Self::{}()", method.fn_name)))
+                    } else if *default_id_from_init {
+                        methods
+                            .iter()
+                            .find(|method| method.usable_init_in_default_id())
+                            .map(|method| {
+                                (method, format!("Self::{}(Self::alloc())", method.fn_name))
+                            })
+                    } else {
+                        None
+                    };
+
+                    if let Some((method, expr)) = default_id {
                         writeln!(f)?;
-                        // Assume `new` methods require no extra features
+                        // Assume `new` and `init` methods require no extra features
                         write!(f, "{}", self.cfg_gate_ln(config))?;
+                        write!(f, "{}", method.availability.cfg_gate_ln())?;
                         writeln!(
                             f,
                             "impl{} DefaultId for {}{} {{",
@@ -1831,7 +2033,7 @@ impl Stmt {
                         )?;
                         writeln!(f, "    #[inline]")?;
                         writeln!(f, "    fn default_id() -> Id<Self> {{")?;
-                        writeln!(f, "        Self::{}()", method.fn_name)?;
+                        writeln!(f, "        {expr}")?;
                         writeln!(f, "    }}")?;
                         writeln!(f, "}}")?;
                     }
@@ -2108,7 +2310,7 @@ impl Stmt {
                     //
                     // <https://developer.apple.com/documentation/swift/grouping-related-objective-c-constants#Declare-Simple-Enumerations>
                     | Some(UnexposedAttr::Enum)
-                    // TODO: Use bitflags! here.
+                    // `NS_OPTIONS` additionally get the bitwise operators.
                     | Some(UnexposedAttr::Options)
                     // TODO: Handle this differently.
                     | Some(UnexposedAttr::ErrorEnum) => {
@@ -2139,11 +2341,12 @@ impl Stmt {
                         writeln!(f, "impl {} {{", id.name)?;
 
                         let required_items = self.required_items();
-                        for (name, availability, expr) in variants {
+                        for (name, documentation, availability, expr) in variants {
                             let implied_features = required_items
                                 .iter()
                                 .map(|item| item.location())
                                 .chain(iter::once(self.location()));
+                            write!(f, "{}", documentation.indented())?;
                             write!(f, "    {}", cfg_gate_ln(expr.required_items(), implied_features, config, self.location()))?;
                             write!(f, "    {availability}")?;
                             let pretty_name = enum_constant_name(&id.name, name);
@@ -2152,8 +2355,69 @@ impl Stmt {
                             }
                             writeln!(f, "    pub const {pretty_name}: Self = Self({expr});")?;
                         }
+
+                        if let Some(UnexposedAttr::Options) = kind {
+                            writeln!(f)?;
+                            writeln!(f, "    /// No flags set.")?;
+                            writeln!(f, "    #[inline]")?;
+                            writeln!(f, "    pub const fn empty() -> Self {{")?;
+                            writeln!(f, "        Self(0)")?;
+                            writeln!(f, "    }}")?;
+                            writeln!(f)?;
+                            writeln!(f, "    /// Whether no flags are set.")?;
+                            writeln!(f, "    #[inline]")?;
+                            writeln!(f, "    pub const fn is_empty(self) -> bool {{")?;
+                            writeln!(f, "        self.0 == 0")?;
+                            writeln!(f, "    }}")?;
+                            writeln!(f)?;
+                            writeln!(f, "    /// Whether all the flags in `other` are also set in `self`.")?;
+                            writeln!(f, "    #[inline]")?;
+                            writeln!(f, "    pub const fn contains(self, other: Self) -> bool {{")?;
+                            writeln!(f, "        self.0 & other.0 == other.0")?;
+                            writeln!(f, "    }}")?;
+                        }
                         writeln!(f, "}}")?;
                         writeln!(f)?;
+
+                        if let Some(UnexposedAttr::Options) = kind {
+                            let ops = [
+                                ("BitOr", "bitor", "|"),
+                                ("BitAnd", "bitand", "&"),
+                                ("BitXor", "bitxor", "^"),
+                            ];
+                            for (trait_, method, op) in ops {
+                                write!(f, "{}", self.cfg_gate_ln(config))?;
+                                writeln!(f, "impl core::ops::{trait_} for {} {{", id.name)?;
+                                writeln!(f, "    type Output = Self;")?;
+                                writeln!(f)?;
+                                writeln!(f, "    #[inline]")?;
+                                writeln!(f, "    fn {method}(self, rhs: Self) -> Self {{")?;
+                                writeln!(f, "        Self(self.0 {op} rhs.0)")?;
+                                writeln!(f, "    }}")?;
+                                writeln!(f, "}}")?;
+                                writeln!(f)?;
+
+                                write!(f, "{}", self.cfg_gate_ln(config))?;
+                                writeln!(f, "impl core::ops::{trait_}Assign for {} {{", id.name)?;
+                                writeln!(f, "    #[inline]")?;
+                                writeln!(f, "    fn {method}_assign(&mut self, rhs: Self) {{")?;
+                                writeln!(f, "        self.0 {op}= rhs.0;")?;
+                                writeln!(f, "    }}")?;
+                                writeln!(f, "}}")?;
+                                writeln!(f)?;
+                            }
+
+                            write!(f, "{}", self.cfg_gate_ln(config))?;
+                            writeln!(f, "impl core::ops::Not for {} {{", id.name)?;
+                            writeln!(f, "    type Output = Self;")?;
+                            writeln!(f)?;
+                            writeln!(f, "    #[inline]")?;
+                            writeln!(f, "    fn not(self) -> Self {{")?;
+                            writeln!(f, "        Self(!self.0)")?;
+                            writeln!(f, "    }}")?;
+                            writeln!(f, "}}")?;
+                            writeln!(f)?;
+                        }
                     }
                     Some(UnexposedAttr::ClosedEnum) => {
                         // SAFETY: `NS_CLOSED_ENUM` is guaranteed to never
@@ -2171,11 +2435,12 @@ impl Stmt {
                         writeln!(f, "pub enum {} {{", id.name)?;
 
                         let required_items = self.required_items();
-                        for (name, availability, expr) in variants {
+                        for (name, documentation, availability, expr) in variants {
                             let implied_features = required_items
                                 .iter()
                                 .map(|item| item.location())
                                 .chain(iter::once(self.location()));
+                            write!(f, "{}", documentation.indented())?;
                             write!(f, "    {}", cfg_gate_ln(expr.required_items(), implied_features, config, self.location()))?;
                             write!(f, "    {availability}")?;
                             let pretty_name = enum_constant_name(&id.name, name);
@@ -2211,11 +2476,13 @@ impl Stmt {
                 }
                 Self::ConstDecl {
                     id,
+                    documentation,
                     availability,
                     ty,
                     value,
                     is_last,
                 } => {
+                    write!(f, "{documentation}")?;
                     write!(f, "{}", self.cfg_gate_ln(config))?;
                     write!(f, "{availability}")?;
                     write!(f, "pub const {}: {} = {value};", id.name, ty.enum_())?;
@@ -2360,3 +2627,112 @@ impl Stmt {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LibraryConfig;
+    use crate::rust_type::Primitive;
+
+    #[test]
+    fn test_unavailable_not_inherited() {
+        let new_method = || Method::dummy_class_instancetype("new");
+
+        // The class itself marks `new` as unavailable, so it is never
+        // re-emitted from any of the superclasses.
+        let mut seen_methods = BTreeSet::from([(true, "new".to_string())]);
+        let mut methods = vec![new_method()];
+        retain_superclass_methods(&mut methods, vec![], &mut seen_methods);
+        assert_eq!(methods, []);
+
+        // The closest superclass marks `new` as unavailable, so it is not
+        // emitted from the superclass' superclass.
+        let mut seen_methods = BTreeSet::new();
+        let mut methods = vec![];
+        retain_superclass_methods(
+            &mut methods,
+            vec![(true, "new".to_string())],
+            &mut seen_methods,
+        );
+        let mut methods = vec![new_method()];
+        retain_superclass_methods(&mut methods, vec![], &mut seen_methods);
+        assert_eq!(methods, []);
+
+        // Otherwise, `new` is inherited.
+        let mut seen_methods = BTreeSet::new();
+        let mut methods = vec![new_method()];
+        retain_superclass_methods(&mut methods, vec![], &mut seen_methods);
+        assert_eq!(methods, [new_method()]);
+    }
+
+    #[test]
+    fn test_toll_free_bridge() {
+        let bridge = TollFreeBridge {
+            cls: "NSString",
+            cf: "objc2_core_foundation::CFString",
+            cfg_gate: "",
+        };
+        let expected = r#"
+impl NSString {
+    /// Get a reference to the toll-free bridged `CFString`.
+    #[inline]
+    pub fn as_cf(&self) -> &objc2_core_foundation::CFString {
+        // SAFETY: `NSString` is toll-free bridged with `CFString`.
+        unsafe { &*(self as *const Self).cast::<objc2_core_foundation::CFString>() }
+    }
+}
+
+impl AsRef<objc2_core_foundation::CFString> for NSString {
+    #[inline]
+    fn as_ref(&self) -> &objc2_core_foundation::CFString {
+        self.as_cf()
+    }
+}
+
+impl<'a> From<&'a NSString> for &'a objc2_core_foundation::CFString {
+    #[inline]
+    fn from(obj: &'a NSString) -> Self {
+        obj.as_cf()
+    }
+}
+
+impl<'a> From<&'a objc2_core_foundation::CFString> for &'a NSString {
+    #[inline]
+    fn from(cf: &'a objc2_core_foundation::CFString) -> Self {
+        // SAFETY: `CFString` is toll-free bridged with `NSString`.
+        unsafe { &*(cf as *const objc2_core_foundation::CFString).cast::<NSString>() }
+    }
+}
+"#;
+        assert_eq!(bridge.to_string(), expected);
+    }
+
+    #[test]
+    fn test_struct_array_field() {
+        let config = Config {
+            libraries: BTreeMap::new(),
+            system: LibraryConfig::default(),
+        };
+        // struct Matrix { float m[16]; };
+        let stmt = Stmt::StructDecl {
+            id: ItemIdentifier::from_raw("Matrix".to_string(), "MyLib".into(), "Matrix".into()),
+            encoding_name: None,
+            availability: Availability::default(),
+            boxable: false,
+            fields: vec![(
+                "m".to_string(),
+                Ty::Array {
+                    element_type: Box::new(Ty::Primitive(Primitive::Float)),
+                    num_elements: 16,
+                },
+            )],
+            sendable: None,
+        };
+        let output = stmt.fmt(&config).to_string();
+        assert!(output.contains("pub m: [c_float; 16],"), "{output}");
+        assert!(
+            output.contains("Encoding::Struct(\"Matrix\", &[<[c_float; 16]>::ENCODING,])"),
+            "{output}"
+        );
+    }
+}
@@ -218,6 +218,34 @@ impl UnexposedAttr {
         }
     }
 
+    /// The name given in `NS_SWIFT_NAME(...)`, if the attribute is that.
+    ///
+    /// This is not part of `UnexposedAttr`, since it is only used for
+    /// methods, and is ignored everywhere else.
+    pub fn parse_swift_name(entity: &Entity<'_>, context: &Context<'_>) -> Option<String> {
+        fn name_from_macro(entity: &Entity<'_>) -> Option<String> {
+            if entity.get_name().as_deref() != Some("NS_SWIFT_NAME") {
+                return None;
+            }
+            let tokens = get_argument_tokens(entity);
+            Some(tokens.iter().map(|token| token.get_spelling()).collect())
+        }
+
+        let location = entity.get_location()?;
+        if let Some(entity) = context
+            .macro_invocations
+            .get(&location.get_spelling_location())
+        {
+            return name_from_macro(entity);
+        }
+        let parsed = location.get_entity()?;
+        if parsed.get_kind() == EntityKind::MacroExpansion {
+            name_from_macro(&parsed)
+        } else {
+            None
+        }
+    }
+
     fn parse_location(location: SourceLocation<'_>) -> Option<Self> {
         if let Some(parsed) = location.get_entity() {
             match parsed.get_kind() {
@@ -213,6 +213,8 @@ impl Encoding {
     ///
     /// For example, you should not rely on two equivalent encodings to have
     /// the same size or ABI - that is provided on a best-effort basis.
+    ///
+    /// Use [`PartialEq`] if you need the encodings to be exactly equal.
     pub fn equivalent_to(&self, other: &Self) -> bool {
         compare_encodings(self, other, NestingLevel::new(), false)
     }
@@ -282,6 +284,11 @@ mod tests {
         assert!(Encoding::Void.equivalent_to_str("Vv"));
         assert!(Encoding::String.equivalent_to_str("*"));
         assert!(Encoding::String.equivalent_to_str("r*"));
+
+        // Equivalent, but not equal
+        let enc = Encoding::Pointer(&Encoding::Int);
+        assert!(enc.equivalent_to_str("r^i"));
+        assert_ne!(enc.to_string(), "r^i");
     }
 
     macro_rules! assert_enc {
@@ -454,6 +461,8 @@ mod tests {
             !Encoding::Pointer(&Encoding::Char);
             !Encoding::Pointer(&Encoding::Pointer(&Encoding::Int));
             "^i";
+            ~"r^i";
+            ~"n^i";
         }
 
         fn array() {
@@ -5,6 +5,9 @@ use objc2::rc::{autoreleasepool, Id};
 use objc2::runtime::{AnyClass, NSObject, Sel};
 use objc2::{class, msg_send, sel};
 
+#[macro_use]
+mod common;
+
 const BYTES: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
 fn empty() {}
@@ -107,33 +110,6 @@ fn autoreleased_nsstring_fast_caller_cleanup_pool_cleanup() -> Id<NSObject> {
     autoreleasepool(|_| retain_autoreleased(autoreleased_nsstring()))
 }
 
-macro_rules! main_with_warmup {
-    ($($f:ident,)+) => {
-        mod warmup_fns {
-            $(
-                #[inline(never)]
-                pub(crate) fn $f() {
-                    let _ = iai::black_box(super::$f());
-                }
-            )+
-        }
-
-        // Required to get DYLD to resolve the stubs on x86_64
-        fn warmup() {
-            $(
-                warmup_fns::$f();
-            )+
-        }
-
-        iai::main! {
-            warmup,
-            $(
-                $f,
-            )+
-        }
-    };
-}
-
 main_with_warmup! {
     // Baseline
     empty,
@@ -0,0 +1,32 @@
+//! Helpers shared between the benchmarks.
+//!
+//! Included with `#[path]` by the benchmarks in the framework crates as well.
+
+/// Run the given benchmark functions with `iai`, after calling each of them
+/// once to warm up.
+macro_rules! main_with_warmup {
+    ($($f:ident,)+) => {
+        mod warmup_fns {
+            $(
+                #[inline(never)]
+                pub(crate) fn $f() {
+                    let _ = iai::black_box(super::$f());
+                }
+            )+
+        }
+
+        // Required to get DYLD to resolve the stubs on x86_64
+        fn warmup() {
+            $(
+                warmup_fns::$f();
+            )+
+        }
+
+        iai::main! {
+            warmup,
+            $(
+                $f,
+            )+
+        }
+    };
+}
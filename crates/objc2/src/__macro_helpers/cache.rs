@@ -105,6 +105,21 @@ impl CachedClass {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_class() {
+        let cache = CachedClass::new();
+        assert!(cache.ptr.load(Ordering::Relaxed).is_null());
+
+        let cls = unsafe { cache.get("NSObject\0") };
+        assert_eq!(cls.name(), "NSObject");
+        assert_eq!(cache.ptr.load(Ordering::Relaxed), cls as *const _ as *mut _);
+
+        // Subsequent lookups return the cached class.
+        assert!(ptr::eq(unsafe { cache.get("NSObject\0") }, cls));
+    }
+
     #[test]
     #[should_panic = "class NonExistantClass could not be found"]
     #[cfg(not(feature = "unstable-static-class"))]
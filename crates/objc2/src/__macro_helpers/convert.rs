@@ -289,6 +289,9 @@ mod tests {
 
     use core::any::TypeId;
 
+    use crate::runtime::NSObject;
+    use crate::{msg_send, sel, ClassType};
+
     #[test]
     fn convert_normally_noop() {
         assert_eq!(
@@ -327,4 +330,32 @@ mod tests {
             crate::encode::Encoding::Char,
         );
     }
+
+    #[test]
+    // Platforms where `BOOL` is not `_Bool`, and can hence have other values
+    #[cfg(any(
+        all(feature = "apple", target_os = "macos", target_arch = "x86_64"),
+        feature = "gnustep-1-7",
+    ))]
+    fn convert_bool_non_zero() {
+        // Any non-zero value is true, like in C.
+        for raw in [1, 2, 42, 0x7f] {
+            assert!(<bool as ConvertReturn>::__from_return(Bool::from_raw(raw)));
+        }
+        assert!(!<bool as ConvertReturn>::__from_return(Bool::from_raw(0)));
+    }
+
+    #[test]
+    fn msg_send_bool() {
+        let obj = NSObject::new();
+
+        let res: bool = unsafe { msg_send![&obj, isKindOfClass: NSObject::class()] };
+        assert!(res);
+        let res: bool = unsafe { msg_send![&obj, isProxy] };
+        assert!(!res);
+
+        // The raw `Bool` can be used as well
+        let res: Bool = unsafe { msg_send![&obj, respondsToSelector: sel!(isProxy)] };
+        assert!(res.is_true());
+    }
 }
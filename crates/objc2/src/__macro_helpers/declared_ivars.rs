@@ -420,9 +420,11 @@ pub(crate) unsafe fn get_initialized_ivar_ptr<T: DeclaredClass>(
 
 #[cfg(test)]
 mod tests {
+    use core::sync::atomic::{AtomicBool, Ordering};
     use std::println;
     use std::sync::Mutex;
 
+    use alloc::boxed::Box;
     use alloc::vec::Vec;
 
     use super::*;
@@ -684,6 +686,60 @@ mod tests {
         *obj.ivars_mut() = Ivar;
     }
 
+    #[test]
+    fn test_boxed_ivar_dropped() {
+        static DROPPED: AtomicBool = AtomicBool::new(false);
+
+        struct SetOnDrop;
+
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                DROPPED.store(true, Ordering::Relaxed);
+            }
+        }
+
+        struct Ivars {
+            _boxed: Box<SetOnDrop>,
+            _obj: Id<__RcTestObject>,
+        }
+
+        declare_class!(
+            struct BoxedIvar;
+
+            unsafe impl ClassType for BoxedIvar {
+                type Super = NSObject;
+                type Mutability = InteriorMutable;
+                const NAME: &'static str = "BoxedIvar";
+            }
+
+            impl DeclaredClass for BoxedIvar {
+                type Ivars = Ivars;
+            }
+
+            unsafe impl BoxedIvar {
+                #[method_id(init)]
+                fn init(this: Allocated<Self>) -> Option<Id<Self>> {
+                    let this = this.set_ivars(Ivars {
+                        _boxed: Box::new(SetOnDrop),
+                        _obj: __RcTestObject::new(),
+                    });
+                    unsafe { msg_send_id![super(this), init] }
+                }
+            }
+        );
+
+        let obj = unsafe { init(BoxedIvar::alloc()) };
+        let mut expected = __ThreadTestData::current();
+        assert!(!DROPPED.load(Ordering::Relaxed));
+
+        // Dropping the object drops the ivars
+        drop(obj);
+        assert!(DROPPED.load(Ordering::Relaxed));
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+
     #[test]
     #[should_panic = "unsupported alignment 16 for `HasIvarWithHighAlignment::Ivars`"]
     fn test_generate_ivar_high_alignment() {
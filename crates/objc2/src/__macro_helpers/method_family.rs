@@ -94,6 +94,23 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_retain_semantics() {
+        assert_eq!(retain_semantics("new"), 1);
+        assert_eq!(retain_semantics("newMethodOnInstance"), 1);
+        assert_eq!(retain_semantics("alloc"), 2);
+        assert_eq!(retain_semantics("allocWithZone:"), 2);
+        assert_eq!(retain_semantics("init"), 3);
+        assert_eq!(retain_semantics("initWithCapacity:"), 3);
+        assert_eq!(retain_semantics("copy"), 4);
+        assert_eq!(retain_semantics("copyWithZone:"), 4);
+        assert_eq!(retain_semantics("mutableCopy"), 4);
+        assert_eq!(retain_semantics("mutableCopyWithZone:"), 4);
+        assert_eq!(retain_semantics("description"), 5);
+        assert_eq!(retain_semantics("newsstandAssetDownload"), 5);
+        assert_eq!(retain_semantics("copying"), 5);
+    }
+
     #[test]
     fn test_in_selector_family() {
         #[track_caller]
@@ -67,6 +67,7 @@ pub trait MsgSendId<T, U> {
 pub trait MsgSendSuperId<T, U> {
     type Inner: ?Sized + RefEncode;
 
+    #[track_caller]
     unsafe fn send_super_message_id<A: ConvertArguments, R: MaybeUnwrap<Input = U>>(
         obj: T,
         superclass: &AnyClass,
@@ -155,6 +156,49 @@ unsafe fn encountered_error<E: Message>(err: *mut E) -> Id<E> {
     unsafe { Id::retain(err) }.expect("error parameter should be set if the method returns NULL")
 }
 
+/// Verify that the returned object is an instance of the class that the
+/// return type represents.
+///
+/// This catches wrongly declared return types early, instead of later
+/// causing undefined behaviour when sending messages to the object.
+///
+/// Only done when debug assertions are enabled, and can be disabled with the
+/// `"relax-return-class-check"` feature.
+#[inline]
+#[track_caller]
+fn check_return_class<U: ?Sized + Message>(obj: &Option<Id<U>>, sel: Sel) {
+    #[cfg(all(debug_assertions, not(feature = "relax-return-class-check")))]
+    if let (Some(obj), Some(expected)) = (obj, U::__expected_class()) {
+        let obj: *const AnyObject = Id::as_ptr(obj).cast();
+        // SAFETY: The object is valid, and all objects are `AnyObject`s.
+        let obj = unsafe { &*obj };
+        // SAFETY: Classes that implement `ClassType` are subclasses of either
+        // `NSObject` or `NSProxy`, and both implement `isProxy`.
+        let is_proxy: bool = unsafe { crate::msg_send![obj, isProxy] };
+        // Proxies forward `isKindOfClass:` to their target, so the answer
+        // would not reflect the proxy class itself; skip the check for them.
+        if !is_proxy {
+            // SAFETY: Same as above, `NSObject` implements `isKindOfClass:`.
+            let is_kind: bool = unsafe { crate::msg_send![obj, isKindOfClass: expected] };
+            if !is_kind {
+                panic_return_class(obj, expected, sel);
+            }
+        }
+    }
+    #[cfg(not(all(debug_assertions, not(feature = "relax-return-class-check"))))]
+    let _ = (obj, sel);
+}
+
+#[cfg(all(debug_assertions, not(feature = "relax-return-class-check")))]
+#[cold]
+#[track_caller]
+fn panic_return_class(obj: &AnyObject, expected: &AnyClass, sel: Sel) -> ! {
+    panic!(
+        "expected {sel} to return an instance of {expected}, but it returned an instance of {}",
+        obj.class(),
+    )
+}
+
 impl<T: MsgSend, U: ?Sized + Message> MsgSendId<T, Option<Id<U>>> for New {
     #[inline]
     unsafe fn send_message_id<A: ConvertArguments, R: MaybeUnwrap<Input = Option<Id<U>>>>(
@@ -168,6 +212,7 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendId<T, Option<Id<U>>> for New {
         // SAFETY: The selector is `new`, so this has +1 retain count
         let obj = unsafe { Id::from_raw(obj) };
 
+        check_return_class(&obj, sel);
         // SAFETY: The object is still valid after a message send to a `new`
         // method - it would not be if the method was `init`.
         R::maybe_unwrap::<Self>(obj, (unsafe { ptr.as_ref() }, sel))
@@ -189,6 +234,7 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendSuperId<T, Option<Id<U>>> for New {
         let obj = unsafe { MsgSend::send_super_message(ptr, superclass, sel, args) };
         // SAFETY: Same as in `send_message_id`
         let obj = unsafe { Id::from_raw(obj) };
+        check_return_class(&obj, sel);
         // SAFETY: Same as in `send_message_id`
         R::maybe_unwrap::<Self>(obj, (unsafe { ptr.as_ref() }, sel))
     }
@@ -267,6 +313,7 @@ impl<T: ?Sized + Message> MsgSendId<Allocated<T>, Option<Id<T>>> for Init {
         let obj = unsafe { MsgSend::send_message(ptr, sel, args) };
         // SAFETY: The selector is `init`, so this has +1 retain count
         let obj = unsafe { Id::from_raw(obj) };
+        check_return_class(&obj, sel);
         R::maybe_unwrap::<Self>(obj, (ptr.cast(), sel))
     }
 }
@@ -293,6 +340,7 @@ impl<T: DeclaredClass> MsgSendSuperId<PartialInit<T>, Option<Id<T>>> for Init {
         }
         // SAFETY: Same as `send_message_id`
         let obj = unsafe { Id::from_raw(ptr) };
+        check_return_class(&obj, sel);
         R::maybe_unwrap::<Self>(obj, (ptr.cast(), sel))
     }
 }
@@ -309,6 +357,7 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendId<T, Option<Id<U>>> for CopyOrMutC
         // SAFETY: The selector is `copy` or `mutableCopy`, so this has +1
         // retain count
         let obj = unsafe { Id::from_raw(obj) };
+        check_return_class(&obj, sel);
         R::maybe_unwrap::<Self>(obj, ())
     }
 }
@@ -327,6 +376,7 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendSuperId<T, Option<Id<U>>> for CopyO
         let obj = unsafe { MsgSend::send_super_message(obj, superclass, sel, args) };
         // SAFETY: Same as in `send_message_id`
         let obj = unsafe { Id::from_raw(obj) };
+        check_return_class(&obj, sel);
         R::maybe_unwrap::<Self>(obj, ())
     }
 }
@@ -348,6 +398,7 @@ impl<T: MsgSend, U: Message> MsgSendId<T, Option<Id<U>>> for Other {
         // `mutableCopy`, so the object must be manually retained.
         let obj = unsafe { Id::retain_autoreleased(obj) };
 
+        check_return_class(&obj, sel);
         // SAFETY: The object is still valid after a message send to a
         // normal method - it would not be if the method was `init`.
         R::maybe_unwrap::<Self>(obj, (unsafe { ptr.as_ref() }, sel))
@@ -369,6 +420,7 @@ impl<T: MsgSend, U: Message> MsgSendSuperId<T, Option<Id<U>>> for Other {
         let obj = unsafe { MsgSend::send_super_message(ptr, superclass, sel, args) };
         // SAFETY: Same as `send_message_id`
         let obj = unsafe { Id::retain_autoreleased(obj) };
+        check_return_class(&obj, sel);
         // SAFETY: Same as `send_message_id`
         R::maybe_unwrap::<Self>(obj, (unsafe { ptr.as_ref() }, sel))
     }
@@ -817,6 +869,22 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_copy_with_zone() {
+        let obj = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let zone: *const NSZone = ptr::null();
+        let _: Id<__RcTestObject> = unsafe { msg_send_id![&obj, copyWithZone: zone] };
+        expected.copy += 1;
+        expected.alloc += 1;
+        expected.init += 1;
+        // No extra retain, the object is returned at +1
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+
     #[test]
     #[should_panic = "failed copying object"]
     fn test_copy_with_null() {
@@ -858,6 +926,22 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_mutable_copy_with_zone() {
+        let obj = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let zone: *const NSZone = ptr::null();
+        let _: Id<__RcTestObject> = unsafe { msg_send_id![&obj, mutableCopyWithZone: zone] };
+        expected.mutable_copy += 1;
+        expected.alloc += 1;
+        expected.init += 1;
+        // No extra retain, the object is returned at +1
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+
     // No method family
 
     #[test]
@@ -875,10 +959,10 @@ mod tests {
         expected.release += 1;
         expected.assert_current();
 
-        let _: Option<Id<__RcTestObject>> = unsafe { msg_send_id![&obj, description] };
+        let _: Option<Id<AnyObject>> = unsafe { msg_send_id![&obj, description] };
         expected.assert_current();
 
-        let _: Option<Id<__RcTestObject>> = unsafe { msg_send_id![super(&obj), description] };
+        let _: Option<Id<AnyObject>> = unsafe { msg_send_id![super(&obj), description] };
         expected.assert_current();
     }
 
@@ -906,4 +990,37 @@ mod tests {
         let obj: *const NSObject = ptr::null();
         let _obj: Id<AnyObject> = unsafe { msg_send_id![obj, description] };
     }
+
+    #[test]
+    #[cfg_attr(
+        all(debug_assertions, not(feature = "relax-return-class-check")),
+        should_panic = "expected self to return an instance of __RcTestObject, but it returned an instance of NSObject"
+    )]
+    #[cfg_attr(
+        not(all(debug_assertions, not(feature = "relax-return-class-check"))),
+        ignore = "only checked with debug assertions"
+    )]
+    fn test_wrong_return_class() {
+        let obj = NSObject::new();
+        let _obj: Id<__RcTestObject> = unsafe { msg_send_id![&obj, self] };
+    }
+
+    #[test]
+    fn test_return_subclass() {
+        let obj = __RcTestObject::new();
+        let _obj: Id<NSObject> = unsafe { msg_send_id![&obj, self] };
+        let _obj: Option<Id<AnyObject>> = unsafe { msg_send_id![&obj, description] };
+    }
+
+    #[test]
+    fn test_normal_returning_superclass() {
+        let obj = __RcTestObject::new();
+        let expected = __ThreadTestData::current();
+
+        let _: Option<Id<NSObject>> = unsafe { msg_send_id![&obj, description] };
+        expected.assert_current();
+
+        let _: Option<Id<NSObject>> = unsafe { msg_send_id![super(&obj), description] };
+        expected.assert_current();
+    }
 }
@@ -39,6 +39,27 @@
 /// [`PhantomData`]: core::marker::PhantomData
 ///
 ///
+/// ## Equality and hashing
+///
+/// Deriving [`PartialEq`][trait@PartialEq], [`Eq`][trait@Eq] and
+/// [`Hash`][trait@core::hash::Hash] uses the superclass' implementation. For
+/// classes that inherit from [`NSObject`], that forwards to the `isEqual:`
+/// and `hash` methods, so classes like `NSString` and `NSNumber` get value
+/// semantics, and can be used as keys in `HashMap` and `HashSet`. Objective-C requires that equal objects have equal hashes, so
+/// the two implementations are consistent with each other.
+///
+/// Most other classes do not override `isEqual:`, and thus get identity
+/// semantics, i.e. two objects are only equal if they are the same object.
+/// Be careful when mixing these in a hashing collection, and avoid mutating
+/// objects while they are used as keys, since that changes their hash.
+///
+/// If you need identity semantics for a class that has value semantics,
+/// compare the pointers with [`Id::as_ptr`] instead.
+///
+/// [`NSObject`]: crate::runtime::NSObject
+/// [`Id::as_ptr`]: crate::rc::Id::as_ptr
+///
+///
 /// ## `ClassType` implementation
 ///
 /// The syntax of this macro neatly documents that it implements the
@@ -422,7 +443,12 @@ macro_rules! __extern_class_impl_traits {
         // `NSProxy` or some other class that ensures this (e.g. the object
         // itself is not a root class).
         $(#[$impl_m])*
-        unsafe impl<$($t)*> $crate::Message for $for {}
+        unsafe impl<$($t)*> $crate::Message for $for {
+            #[inline]
+            fn __expected_class() -> $crate::__macro_helpers::Option<&'static $crate::runtime::AnyClass> {
+                $crate::__macro_helpers::Some(<Self as $crate::ClassType>::class())
+            }
+        }
 
         // SAFETY: An instance can always be _used_ in exactly the same way as
         // its superclasses (though not necessarily _constructed_ in the same
@@ -118,6 +118,12 @@
 ///         // Since the selector specifies "_", the return type is assumed to
 ///         // be `Result`.
 ///         pub fn with_error(&self) -> Result<(), Id<NSError>>;
+///
+///         // Methods with a body are output unchanged, which is useful for
+///         // adding convenience methods next to the bindings.
+///         pub fn has_foo(&self) -> bool {
+///             self.foo() != 0
+///         }
 ///     }
 /// );
 /// ```
@@ -178,6 +184,12 @@
 ///     pub fn with_error(&self) -> Result<(), Id<NSError>> {
 ///         unsafe { msg_send![self, withError: _] }
 ///     }
+///
+///     // Methods with a body are output unchanged, which is useful for
+///     // adding convenience methods next to the bindings.
+///     pub fn has_foo(&self) -> bool {
+///         self.foo() != 0
+///     }
 /// }
 /// ```
 ///
@@ -706,6 +706,9 @@ macro_rules! __class_inner {
 /// All arguments, as well as the return type, must implement [`Encode`] (bar
 /// the exceptions below).
 ///
+/// Nullable object parameters can be passed as `Option<&T>`, where [`None`]
+/// is passed as `nil`.
+///
 /// If the last argument is the special marker `_`, the macro will return a
 /// `Result<(), Id<E>>`, see below.
 ///
@@ -791,6 +794,32 @@ macro_rules! __class_inner {
 /// [swift-error]: https://developer.apple.com/documentation/swift/about-imported-cocoa-error-parameters
 ///
 ///
+/// # Struct and floating-point return types
+///
+/// On Apple platforms, methods that return large structs or certain
+/// floating-point values must be called through a special variant of
+/// `objc_msgSend`. This macro automatically selects the correct variant at
+/// compile-time, based on the size and [encoding] of the return type:
+///
+/// - `aarch64`: `objc_msgSend` is always used.
+/// - `x86_64`: Structs larger than 16 bytes use `objc_msgSend_stret`.
+///   `long double` uses `objc_msgSend_fpret`, and `_Complex long double`
+///   uses `objc_msgSend_fp2ret`.
+/// - `x86`: Values that are not 0, 1, 2, 4 or 8 bytes large use
+///   `objc_msgSend_stret`. `float`, `double` and `long double` use
+///   `objc_msgSend_fpret`.
+/// - `arm`: Values larger than 4 bytes use `objc_msgSend_stret`, except for
+///   `long long`, `unsigned long long` and `double`.
+///
+/// Messages to `super` use `objc_msgSendSuper_stret` in the same cases as
+/// `objc_msgSend_stret` (there is no `fpret` variant for `super`).
+///
+/// On other runtimes, the method implementation is looked up first, and then
+/// called directly, so no special handling is required.
+///
+/// [encoding]: crate::Encode::ENCODING
+///
+///
 /// # Panics
 ///
 /// Panics if the `"catch-all"` feature is enabled and the Objective-C method
@@ -1145,6 +1174,11 @@ macro_rules! msg_send_bool {
 /// Panics if the return type is specified as `Id<_, _>` and the method
 /// returned NULL.
 ///
+/// With debug assertions enabled, this also panics if the returned object is
+/// not an instance of the class that the return type represents (only checked
+/// for types declared with [`extern_class!`] or [`declare_class!`]). This
+/// can be disabled with the `"relax-return-class-check"` feature.
+///
 /// Additional panicking cases are documented in [`msg_send!`].
 ///
 ///
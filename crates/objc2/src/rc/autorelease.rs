@@ -40,6 +40,24 @@ impl Pool {
         Self { context }
     }
 
+    /// Drains the pool after the closure has returned normally.
+    ///
+    /// On Apple platforms (except 32-bit macOS), this is done in `Drop`
+    /// instead.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as `objc_autoreleasePoolPop`; the pool must be the innermost
+    /// pool.
+    #[cfg(not(all(feature = "apple", not(all(target_os = "macos", target_arch = "x86")))))]
+    #[inline]
+    unsafe fn drain(self) {
+        unsafe { ffi::objc_autoreleasePoolPop(self.context) }
+    }
+}
+
+impl Drop for Pool {
     /// Drains the autoreleasepool.
     ///
     /// The [clang documentation] says that `@autoreleasepool` blocks are not
@@ -48,36 +66,40 @@ impl Pool {
     /// > Not draining the pool during an unwind is apparently required by the
     /// > Objective-C exceptions implementation.
     ///
-    /// We _would_ really like to do this anyway whenever possible, since the
-    /// unwind is probably caused by Rust, and forgetting to pop the pool will
-    /// likely leak memory.
-    ///
-    /// The above statement was true in the past, but since [revision `551.1`]
-    /// of objc4 (ships with MacOS 10.9) the exception is now retained when
-    /// `@throw` is encountered (on __OBJC2__, so e.g. not on macOS 32bit).
+    /// This is because the exception object may have been autoreleased into
+    /// the pool being drained. Since [revision `551.1`] of objc4 (ships with
+    /// macOS 10.9), the exception is instead retained when `@throw` is
+    /// encountered (on __OBJC2__, so e.g. not on macOS 32bit).
     ///
-    /// So in the future, once we drop support for older versions, we should
-    /// move this to `Drop`.
+    /// So on Apple platforms, we drain the pool when unwinding as well, since
+    /// the unwind is probably caused by Rust, and forgetting to pop the pool
+    /// will likely leak memory. 32-bit macOS and other runtimes may still
+    /// autorelease the exception object, so there the pool is only drained
+    /// when the closure returns normally.
     ///
     /// [clang documentation]: https://clang.llvm.org/docs/AutomaticReferenceCounting.html#autoreleasepool
     /// [revision `551.1`]: https://github.com/apple-oss-distributions/objc4/blob/objc4-551.1/runtime/objc-exception.mm#L516
-    #[inline]
-    unsafe fn drain(self) {
-        unsafe { ffi::objc_autoreleasePoolPop(self.context) }
-    }
-}
-
-impl Drop for Pool {
     #[inline]
     fn drop(&mut self) {
         #[cfg(all(debug_assertions, not(feature = "unstable-autoreleasesafe")))]
         POOLS.with(|c| {
-            assert_eq!(
-                c.borrow_mut().pop(),
-                Some(self.context),
-                "popped pool that was not the innermost pool"
-            )
+            let popped = c.borrow_mut().pop();
+            // Panicking while already unwinding would abort the process.
+            if !std::thread::panicking() {
+                assert_eq!(
+                    popped,
+                    Some(self.context),
+                    "popped pool that was not the innermost pool"
+                );
+            }
         });
+        // SAFETY: The pool was pushed in `Pool::new`, and the caller of that
+        // upholds that the pools are dropped in the reverse order of when
+        // they were created.
+        #[cfg(all(feature = "apple", not(all(target_os = "macos", target_arch = "x86"))))]
+        unsafe {
+            ffi::objc_autoreleasePoolPop(self.context)
+        }
     }
 }
 
@@ -435,8 +457,15 @@ where
     //   This would not work if we e.g. allowed users to create pools on the
     //   stack, since they could then safely control the drop order.
     let pool = unsafe { Pool::new() };
+    // On Apple platforms (except 32-bit macOS), the pool is drained when
+    // `pool` is dropped, also if `f` panics.
     let res = f(AutoreleasePool::new(Some(&pool)));
-    unsafe { pool.drain() };
+    // SAFETY: Any pools created inside `f` have been dropped by now, so this
+    // is the innermost pool.
+    #[cfg(not(all(feature = "apple", not(all(target_os = "macos", target_arch = "x86")))))]
+    unsafe {
+        pool.drain()
+    };
     res
 }
 
@@ -523,8 +552,9 @@ mod tests {
 
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
-    use super::{AutoreleasePool, AutoreleaseSafe};
-    use crate::runtime::AnyObject;
+    use super::{autoreleasepool, AutoreleasePool, AutoreleaseSafe};
+    use crate::rc::{__RcTestObject, __ThreadTestData, Id};
+    use crate::runtime::{AnyObject, NSObjectProtocol};
 
     #[test]
     fn auto_traits() {
@@ -561,4 +591,47 @@ mod tests {
     fn assert_zst() {
         assert_eq!(mem::size_of::<AutoreleasePool<'static>>(), 0);
     }
+
+    #[test]
+    fn test_return_value() {
+        let mut expected = __ThreadTestData::current();
+
+        let obj: Id<__RcTestObject> = autoreleasepool(|_| __RcTestObject::new());
+        expected.alloc += 1;
+        expected.init += 1;
+        expected.assert_current();
+        assert_eq!(obj.retainCount(), 1);
+
+        drop(obj);
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+
+    #[test]
+    #[cfg(all(feature = "apple", not(all(target_os = "macos", target_arch = "x86"))))]
+    fn test_drained_on_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let obj = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            autoreleasepool(|pool| {
+                let _ = Id::autorelease(obj, pool);
+                panic!("panic inside pool");
+            })
+        }));
+        assert!(res.is_err());
+        expected.autorelease += 1;
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+
+        // The pool was popped, so a new pool is now the innermost pool.
+        autoreleasepool(|pool| {
+            let obj = __RcTestObject::new();
+            let _ = Id::autorelease(obj, pool);
+        });
+    }
 }
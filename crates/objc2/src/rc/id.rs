@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::fmt;
 use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
@@ -235,6 +236,15 @@ impl<T: ?Sized + Message> Id<T> {
     /// This is effectively the opposite of [`Id::from_raw`], see that for
     /// more details on when this function is useful.
     ///
+    /// The two are intended to be used as a pair, similar to
+    /// [`Box::into_raw`] and [`Box::from_raw`], for example to pass
+    /// ownership of an object through the `void*` context pointer of a C
+    /// callback. If the pointer is never passed back to `Id::from_raw`, the
+    /// object is leaked.
+    ///
+    /// [`Box::into_raw`]: alloc::boxed::Box::into_raw
+    /// [`Box::from_raw`]: alloc::boxed::Box::from_raw
+    ///
     ///
     /// # Examples
     ///
@@ -258,9 +268,27 @@ impl<T: ?Sized + Message> Id<T> {
     ///
     /// The pointer is valid for at least as long as the `Id` is held.
     ///
+    /// This does not consume the `Id`, and does not change the retain count
+    /// of the object, so it is useful for passing the object to foreign
+    /// code that does not take ownership. Use [`Id::into_raw`] if you want
+    /// to transfer ownership instead.
+    ///
     /// See [`Id::as_mut_ptr`] for the mutable equivalent.
     ///
     /// This is an associated method, and must be called as `Id::as_ptr(obj)`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2::rc::Id;
+    /// use objc2::runtime::NSObject;
+    ///
+    /// let obj = NSObject::new();
+    /// let ptr: *const NSObject = Id::as_ptr(&obj);
+    /// // The pointer is the same as the reference that `Id` derefs to.
+    /// assert_eq!(ptr, &*obj as *const NSObject);
+    /// ```
     #[inline]
     pub fn as_ptr(this: &Self) -> *const T {
         this.ptr.as_ptr()
@@ -270,7 +298,12 @@ impl<T: ?Sized + Message> Id<T> {
     ///
     /// The pointer is valid for at least as long as the `Id` is held.
     ///
-    /// See [`Id::as_ptr`] for the immutable equivalent.
+    /// Like [`Id::as_ptr`], this does not change the retain count of the
+    /// object.
+    ///
+    /// This is only available for objects that are [`IsMutable`], since the
+    /// pointer may be used to mutate the object, and that would not be sound
+    /// if other references to it could exist.
     ///
     /// This is an associated method, and must be called as
     /// `Id::as_mut_ptr(obj)`.
@@ -378,6 +411,53 @@ impl<T: Message> Id<T> {
         unsafe { Self::from_raw(res) }
     }
 
+    /// Retain each of the pointers in the slice, and collect them into a
+    /// [`Vec`].
+    ///
+    /// This is a convenience for when you've received several objects from
+    /// e.g. a C array, and want to ensure that they stay around. The objects
+    /// are released again when the returned `Vec` is dropped.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the pointers are NULL. The objects that were
+    /// retained before the NULL pointer are released again before panicking.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Id::retain`], for each pointer in the slice. In particular,
+    /// if the object is mutable, the same pointer must not appear twice in
+    /// the slice.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2::rc::Id;
+    /// use objc2::runtime::NSObject;
+    ///
+    /// let obj1 = NSObject::new();
+    /// let obj2 = NSObject::new();
+    /// let ptrs = [Id::as_ptr(&obj1) as *mut NSObject, Id::as_ptr(&obj2) as *mut NSObject];
+    ///
+    /// // SAFETY: The pointers are valid, and `NSObject` is not mutable.
+    /// let objs = unsafe { Id::retain_slice(&ptrs) };
+    /// assert_eq!(objs, [obj1, obj2]);
+    /// ```
+    #[doc(alias = "objc_retain")]
+    pub unsafe fn retain_slice(ptrs: &[*mut T]) -> Vec<Id<T>> {
+        // If we panic partway through, the `Vec` that is being collected
+        // into is dropped, which releases the objects retained so far.
+        ptrs.iter()
+            .map(|&ptr| {
+                // SAFETY: Upheld by the caller
+                unsafe { Self::retain(ptr) }.unwrap_or_else(|| retain_slice_null())
+            })
+            .collect()
+    }
+
     /// Retains a previously autoreleased object pointer.
     ///
     /// This is useful when calling Objective-C methods that return
@@ -387,6 +467,15 @@ impl<T: Message> Id<T> {
     /// sometimes avoid putting the object into the autorelease pool, possibly
     /// yielding increased speed and reducing memory pressure.
     ///
+    /// To summarize which function to use for the return value of a method:
+    /// - Methods in the `alloc`, `new`, `copy`, `mutableCopy` and `init`
+    ///   families, and methods with the `ns_returns_retained` attribute,
+    ///   return +1 retain count; use [`Id::from_raw`].
+    /// - Other methods return +0 retain count, usually an autoreleased
+    ///   object; use this function directly after the message send.
+    /// - Pointers that were not returned from a method, such as arguments
+    ///   given to a callback, are not autoreleased; use [`Id::retain`].
+    ///
     /// Note: This relies heavily on being inlined right after [`msg_send!`],
     /// be careful to not accidentally require instructions between these.
     ///
@@ -650,6 +739,12 @@ impl<T: Message> Id<T> {
     }
 }
 
+// Intentionally not `#[track_caller]`, since it's called from a closure.
+#[cold]
+fn retain_slice_null() -> ! {
+    panic!("tried to retain a NULL pointer in `Id::retain_slice`")
+}
+
 impl<T: ClassType + 'static> Id<T>
 where
     T::Super: 'static,
@@ -875,7 +970,9 @@ struct TestDocIdWithNonClassType {
 
 #[cfg(test)]
 mod tests {
+    use core::ffi::c_void;
     use core::mem::size_of;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
 
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
@@ -937,6 +1034,30 @@ mod tests {
         assert_impl_all!(Id<MutableSendSyncObject>: Send, Sync);
     }
 
+    #[test]
+    fn deref_mut_only_when_mutable() {
+        declare_class!(
+            struct IdTestMutableObject;
+
+            unsafe impl ClassType for IdTestMutableObject {
+                type Super = NSObject;
+                type Mutability = Mutable;
+                const NAME: &'static str = "IdTestMutableObject";
+            }
+
+            impl DeclaredClass for IdTestMutableObject {}
+        );
+
+        assert_impl_all!(Id<IdTestMutableObject>: DerefMut);
+        // Shared objects must not give out mutable references
+        assert_not_impl_any!(Id<NSObject>: DerefMut);
+        assert_not_impl_any!(Id<AnyObject>: DerefMut);
+
+        let mut obj: Id<IdTestMutableObject> =
+            unsafe { crate::msg_send_id![IdTestMutableObject::class(), new] };
+        let _: &mut IdTestMutableObject = &mut obj;
+    }
+
     #[test]
     fn test_drop() {
         let mut expected = __ThreadTestData::current();
@@ -1018,6 +1139,87 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_into_raw_from_raw() {
+        let obj = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let ptr: *mut c_void = Id::into_raw(obj).cast();
+        expected.assert_current();
+
+        // SAFETY: The pointer came from `Id::into_raw` above.
+        let obj = unsafe { Id::from_raw(ptr.cast::<__RcTestObject>()) }.unwrap();
+        expected.assert_current();
+        assert_eq!(obj.retainCount(), 1);
+
+        drop(obj);
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+
+        // SAFETY: NULL is allowed.
+        assert!(unsafe { Id::<__RcTestObject>::from_raw(ptr::null_mut()) }.is_none());
+    }
+
+    #[test]
+    fn test_as_ptr() {
+        let obj = __RcTestObject::new();
+        let expected = __ThreadTestData::current();
+
+        let ptr = Id::as_ptr(&obj);
+        assert_eq!(ptr, &*obj as *const __RcTestObject);
+        expected.assert_current();
+        assert_eq!(obj.retainCount(), 1);
+    }
+
+    #[test]
+    fn test_retain_slice() {
+        let obj1 = __RcTestObject::new();
+        let obj2 = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let ptrs = [
+            Id::as_ptr(&obj1) as *mut __RcTestObject,
+            Id::as_ptr(&obj2) as *mut __RcTestObject,
+            Id::as_ptr(&obj1) as *mut __RcTestObject,
+        ];
+        let objs = unsafe { Id::retain_slice(&ptrs) };
+        expected.retain += 3;
+        expected.assert_current();
+        assert_eq!(objs.len(), 3);
+        assert_eq!(obj1.retainCount(), 3);
+        assert_eq!(obj2.retainCount(), 2);
+
+        drop(objs);
+        expected.release += 3;
+        expected.assert_current();
+    }
+
+    #[test]
+    fn test_retain_slice_null() {
+        let obj = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let ptrs = [Id::as_ptr(&obj) as *mut __RcTestObject, ptr::null_mut()];
+        let res = catch_unwind(AssertUnwindSafe(|| unsafe { Id::retain_slice(&ptrs) }));
+        assert!(res.is_err());
+
+        // The already retained object is released again.
+        expected.retain += 1;
+        expected.release += 1;
+        expected.assert_current();
+        assert_eq!(obj.retainCount(), 1);
+    }
+
+    #[test]
+    fn test_retain_null() {
+        let expected = __ThreadTestData::current();
+        // SAFETY: NULL is allowed.
+        assert!(unsafe { Id::<__RcTestObject>::retain(ptr::null_mut()) }.is_none());
+        assert!(unsafe { Id::<__RcTestObject>::retain_autoreleased(ptr::null_mut()) }.is_none());
+        expected.assert_current();
+    }
+
     #[test]
     fn test_retain_autoreleased_works_as_retain() {
         let obj = __RcTestObject::new();
@@ -9,7 +9,8 @@
 //!
 //! Weak references may be created using the [`WeakId`] struct; these will not
 //! retain the object, but one can attempt to load them and obtain an `Id`, or
-//! safely fail if the object has been deallocated.
+//! safely fail if the object has been deallocated. [`WeakIdVec`] can be used
+//! to hold many of these, e.g. for a list of delegates.
 //!
 //! See [the clang documentation][clang-arc] and [the Apple article on memory
 //! management][mem-mgmt] (similar document exists [for Core Foundation][cf])
@@ -55,6 +56,7 @@ mod id_forwarding_impls;
 mod id_traits;
 mod test_object;
 mod weak_id;
+mod weak_id_vec;
 
 pub use self::allocated_partial_init::{Allocated, PartialInit};
 pub use self::autorelease::{
@@ -65,3 +67,4 @@ pub use self::id_traits::{DefaultId, IdFromIterator, IdIntoIterator};
 #[doc(hidden)]
 pub use self::test_object::{__RcTestObject, __ThreadTestData};
 pub use self::weak_id::WeakId;
+pub use self::weak_id_vec::WeakIdVec;
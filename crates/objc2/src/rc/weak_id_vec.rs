@@ -0,0 +1,173 @@
+use alloc::vec::{self, Vec};
+use core::fmt;
+
+use super::{Id, WeakId};
+use crate::mutability::IsRetainable;
+use crate::Message;
+
+/// A list of weak pointers to Objective-C objects, that removes the
+/// deallocated objects as it goes.
+///
+/// This is useful for lists of delegates, listeners or observers, where the
+/// list shouldn't keep the objects alive.
+///
+/// Objects may be deallocated at any time (even on other threads), so there
+/// is no way to know exactly how many objects in the list are alive without
+/// loading them; instead, [`iter_live`][Self::iter_live] loads each object,
+/// and removes the entries whose object has been deallocated.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::rc::WeakIdVec;
+/// use objc2::runtime::NSObject;
+///
+/// let obj1 = NSObject::new();
+/// let obj2 = NSObject::new();
+///
+/// let mut listeners = WeakIdVec::new();
+/// listeners.push(&*obj1);
+/// listeners.push(&*obj2);
+/// assert_eq!(listeners.len_approx(), 2);
+///
+/// drop(obj1);
+/// assert_eq!(listeners.iter_live().count(), 1);
+/// assert_eq!(listeners.len_approx(), 1);
+/// ```
+pub struct WeakIdVec<T: ?Sized> {
+    items: Vec<WeakId<T>>,
+}
+
+impl<T: Message> WeakIdVec<T> {
+    /// Construct a new, empty list.
+    #[inline]
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Add a weak pointer to the given object to the end of the list.
+    #[inline]
+    pub fn push(&mut self, obj: &T)
+    where
+        T: IsRetainable,
+    {
+        self.items.push(WeakId::new(obj));
+    }
+
+    /// Load the objects that are still alive.
+    ///
+    /// Entries whose object has been deallocated are removed from the list,
+    /// the rest are retained and returned in the order they were pushed.
+    ///
+    /// Since the objects are loaded up front, the returned iterator keeps
+    /// them alive until it is dropped, even if all other references to them
+    /// go away in the meantime.
+    #[doc(alias = "objc_loadWeakRetained")]
+    pub fn iter_live(&mut self) -> vec::IntoIter<Id<T>> {
+        let mut live = Vec::with_capacity(self.items.len());
+        self.items.retain(|weak| {
+            if let Some(obj) = weak.load() {
+                live.push(obj);
+                true
+            } else {
+                false
+            }
+        });
+        live.into_iter()
+    }
+
+    /// The number of entries in the list.
+    ///
+    /// This is only an upper bound on the number of live objects, since it
+    /// includes objects that have been deallocated since the list was last
+    /// compacted by [`iter_live`][Self::iter_live].
+    #[inline]
+    pub fn len_approx(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<T: Message> Default for WeakIdVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for WeakIdVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like `WeakId`, we don't try to debug-print the values.
+        f.debug_list().entries(&self.items).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc::{__RcTestObject, __ThreadTestData};
+    use crate::runtime::NSObjectProtocol;
+
+    #[test]
+    fn test_compacts() {
+        let obj1 = __RcTestObject::new();
+        let obj2 = __RcTestObject::new();
+        let obj3 = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let mut list = WeakIdVec::new();
+        list.push(&*obj1);
+        list.push(&*obj2);
+        list.push(&*obj3);
+        expected.assert_current();
+        assert_eq!(list.len_approx(), 3);
+
+        drop(obj2);
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+        assert_eq!(list.len_approx(), 3);
+
+        let live: Vec<_> = list.iter_live().collect();
+        expected.try_retain += 2;
+        expected.assert_current();
+        assert_eq!(live.len(), 2);
+        assert!(core::ptr::eq(&*live[0], &*obj1));
+        assert!(core::ptr::eq(&*live[1], &*obj3));
+        assert_eq!(list.len_approx(), 2);
+
+        drop(live);
+        drop(obj1);
+        drop(obj3);
+        expected.release += 4;
+        expected.drop += 2;
+        expected.assert_current();
+
+        if cfg!(not(feature = "gnustep-1-7")) {
+            // See `test_weak` in `weak_id.rs`.
+            assert_eq!(list.iter_live().count(), 0);
+            assert_eq!(list.len_approx(), 0);
+        }
+    }
+
+    #[test]
+    fn test_live_objects_kept_alive() {
+        let obj = __RcTestObject::new();
+        let mut list = WeakIdVec::new();
+        list.push(&*obj);
+
+        let mut iter = list.iter_live();
+        drop(obj);
+        // The object is retained by the iterator.
+        let obj = iter.next().unwrap();
+        assert_eq!(obj.retainCount(), 1);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut list = WeakIdVec::<__RcTestObject>::default();
+        assert_eq!(list.len_approx(), 0);
+        assert_eq!(list.iter_live().count(), 0);
+    }
+}
@@ -0,0 +1,289 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::cell::UnsafeCell;
+use core::ffi::c_void;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr;
+
+use crate::mutability::Immutable;
+use crate::rc::Id;
+use crate::runtime::{AnyObject, NSObject};
+use crate::{declare_class, ffi, msg_send_id, ClassType, DeclaredClass};
+
+declare_class!(
+    /// The object that owns a value stored using [`AssociatedObject`].
+    ///
+    /// The runtime releases this when the object that it is associated with
+    /// is deallocated, which in turn drops the value.
+    struct AssociatedValue;
+
+    unsafe impl ClassType for AssociatedValue {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        // Include the version, so that several semver-incompatible copies
+        // of `objc2` can be linked into the same binary.
+        const NAME: &'static str = concat!("__objc2_AssociatedValue_", env!("CARGO_PKG_VERSION"));
+    }
+
+    impl DeclaredClass for AssociatedValue {
+        type Ivars = Box<dyn Any + Send + Sync>;
+    }
+);
+
+impl AssociatedValue {
+    fn new(value: Box<dyn Any + Send + Sync>) -> Id<Self> {
+        let this = Self::alloc().set_ivars(value);
+        // SAFETY: `NSObject`'s `init` is safe to call.
+        unsafe { msg_send_id![super(this), init] }
+    }
+}
+
+/// A key for attaching Rust data to existing Objective-C objects.
+///
+/// This uses the runtime's associated objects, which makes it possible to
+/// store extra state on objects of classes that you do not control, without
+/// having to subclass them.
+///
+/// The key is the address of the `AssociatedObject`, so it must be placed in
+/// a `static`, and each `static` refers to a distinct value on the object.
+/// Using a `const` is not allowed, since each use of it could end up at a
+/// different address.
+///
+/// ```compile_fail
+/// use objc2::runtime::{AssociatedObject, NSObject};
+///
+/// const NAME: AssociatedObject<String> = AssociatedObject::new();
+///
+/// let obj = NSObject::new();
+/// NAME.set(&obj, String::from("foo"));
+/// ```
+///
+///
+/// # Thread safety
+///
+/// Objects can be shared between threads, so the value may be accessed, and
+/// eventually dropped, on a different thread than the one it was set on. To
+/// account for that, the value is required to be [`Send`] and [`Sync`].
+///
+/// Setting and getting the value is atomic, but a reference obtained with
+/// [`get`][Self::get] is not updated if the value is replaced afterwards.
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::runtime::{AssociatedObject, NSObject};
+///
+/// static NAME: AssociatedObject<String> = AssociatedObject::new();
+///
+/// let obj = NSObject::new();
+/// assert!(NAME.get(&obj).is_none());
+///
+/// NAME.set(&obj, String::from("foo"));
+/// assert_eq!(*NAME.get(&obj).unwrap(), "foo");
+///
+/// // The string is dropped when `obj` is deallocated.
+/// drop(obj);
+/// ```
+#[doc(alias = "objc_setAssociatedObject")]
+#[doc(alias = "objc_getAssociatedObject")]
+// Copying the key would change its address
+#[allow(missing_copy_implementations)]
+pub struct AssociatedObject<T: 'static> {
+    // Zero-sized statics are not guaranteed to have distinct addresses.
+    //
+    // The `UnsafeCell` prevents the key from being promoted to a `'static`
+    // constant, which would allow using it from a `const`.
+    _unique: UnsafeCell<u8>,
+    p: PhantomData<fn() -> T>,
+}
+
+// SAFETY: The cell is never accessed, only its address is used.
+unsafe impl<T: 'static> Sync for AssociatedObject<T> {}
+
+impl<T: 'static> AssociatedObject<T> {
+    /// Create a new key.
+    ///
+    /// This should be used to initialize a `static`.
+    #[inline]
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            _unique: UnsafeCell::new(0),
+            p: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn key(&'static self) -> *const c_void {
+        let ptr: *const Self = self;
+        ptr.cast()
+    }
+}
+
+/// Remove all associated values from the object.
+///
+/// Note that this also removes values that were set by other libraries, so
+/// you should generally prefer [`AssociatedObject::remove`].
+#[doc(alias = "objc_removeAssociatedObjects")]
+pub fn remove_associated_objects(obj: &AnyObject) {
+    // SAFETY: The object is valid.
+    unsafe { ffi::objc_removeAssociatedObjects(obj.as_ptr() as *mut _) }
+}
+
+impl<T: Send + Sync + 'static> AssociatedObject<T> {
+    fn set_inner(&'static self, obj: &AnyObject, value: *const AssociatedValue) {
+        // SAFETY: The key is unique to `T`, and the value is either NULL or
+        // a valid object. The association is strong, so the runtime retains
+        // the value for as long as the association exists.
+        unsafe {
+            ffi::objc_setAssociatedObject(
+                obj.as_ptr() as *mut _,
+                self.key(),
+                value as *mut _,
+                ffi::OBJC_ASSOCIATION_RETAIN,
+            )
+        }
+    }
+
+    /// Attach `value` to the object, replacing any previous value.
+    ///
+    /// The value is dropped when the object is deallocated, or when the
+    /// value is replaced or removed and all references to it are gone.
+    #[doc(alias = "objc_setAssociatedObject")]
+    pub fn set(&'static self, obj: &AnyObject, value: T) {
+        let value = AssociatedValue::new(Box::new(value));
+        self.set_inner(obj, Id::as_ptr(&value));
+    }
+
+    /// Get the value that was previously attached to the object, if any.
+    ///
+    /// The returned reference keeps the value alive, even if it is replaced
+    /// or removed in the meantime.
+    #[doc(alias = "objc_getAssociatedObject")]
+    pub fn get(&'static self, obj: &AnyObject) -> Option<AssociatedRef<T>> {
+        // SAFETY: The key is valid.
+        let value = unsafe { ffi::objc_getAssociatedObject(obj.as_ptr(), self.key()) };
+        // SAFETY: Values for this key are only ever set by `set_inner`,
+        // which means that the object is an `AssociatedValue`.
+        //
+        // The association is atomic, so the object is not deallocated
+        // before we've had a chance to retain it.
+        let holder = unsafe { Id::retain(value as *mut AssociatedValue) }?;
+        Some(AssociatedRef {
+            holder,
+            p: PhantomData,
+        })
+    }
+
+    /// Remove the value that was attached to the object, if any.
+    #[doc(alias = "objc_setAssociatedObject")]
+    pub fn remove(&'static self, obj: &AnyObject) {
+        self.set_inner(obj, ptr::null());
+    }
+}
+
+impl<T: 'static> fmt::Debug for AssociatedObject<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssociatedObject")
+            .field("key", &(self as *const Self))
+            .finish_non_exhaustive()
+    }
+}
+
+/// A reference to a value stored using [`AssociatedObject`].
+///
+/// This is created with [`AssociatedObject::get`].
+pub struct AssociatedRef<T: 'static> {
+    holder: Id<AssociatedValue>,
+    p: PhantomData<T>,
+}
+
+impl<T: 'static> Deref for AssociatedRef<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.holder
+            .ivars()
+            .downcast_ref()
+            .expect("associated value had the wrong type")
+    }
+}
+
+impl<T: fmt::Debug + 'static> fmt::Debug for AssociatedRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_set_get_remove() {
+        static KEY: AssociatedObject<u32> = AssociatedObject::new();
+        static OTHER_KEY: AssociatedObject<u32> = AssociatedObject::new();
+
+        let obj = NSObject::new();
+        assert!(KEY.get(&obj).is_none());
+
+        KEY.set(&obj, 42);
+        assert_eq!(*KEY.get(&obj).unwrap(), 42);
+        assert!(OTHER_KEY.get(&obj).is_none());
+
+        let old = KEY.get(&obj).unwrap();
+        KEY.set(&obj, 43);
+        assert_eq!(*old, 42);
+        assert_eq!(*KEY.get(&obj).unwrap(), 43);
+
+        KEY.remove(&obj);
+        assert!(KEY.get(&obj).is_none());
+        assert_eq!(*old, 42);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        static KEY1: AssociatedObject<String> = AssociatedObject::new();
+        static KEY2: AssociatedObject<String> = AssociatedObject::new();
+
+        let obj = NSObject::new();
+        KEY1.set(&obj, String::from("a"));
+        KEY2.set(&obj, String::from("b"));
+        assert_eq!(*KEY1.get(&obj).unwrap(), "a");
+        assert_eq!(*KEY2.get(&obj).unwrap(), "b");
+
+        remove_associated_objects(&obj);
+        assert!(KEY1.get(&obj).is_none());
+        assert!(KEY2.get(&obj).is_none());
+    }
+
+    #[test]
+    fn test_dropped_with_object() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct DropCounter;
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        static KEY: AssociatedObject<DropCounter> = AssociatedObject::new();
+
+        let obj = NSObject::new();
+        KEY.set(&obj, DropCounter);
+        KEY.set(&obj, DropCounter);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+        drop(obj);
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+    }
+}
@@ -186,6 +186,23 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "apple")]
+    fn test_encoding_per_target() {
+        let expected = if cfg!(target_arch = "aarch64") {
+            "B"
+        } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+            "c"
+        } else {
+            return;
+        };
+        assert_eq!(Bool::ENCODING.to_string(), expected);
+        assert_eq!(Bool::ENCODING_REF.to_string(), format!("^{expected}"));
+
+        assert!(Bool::new(true).as_bool());
+        assert!(!Bool::new(false).as_bool());
+    }
+
     #[test]
     fn test_impls() {
         let b: Bool = Default::default();
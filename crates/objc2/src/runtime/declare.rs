@@ -154,6 +154,18 @@ impl<T> Log2Alignment for T {
 /// let n: u32 = unsafe { msg_send![&obj, number] };
 /// assert_eq!(n, 12);
 /// ```
+///
+///
+/// # Thread safety
+///
+/// The Objective-C runtime internally synchronizes the creation and
+/// registration of classes (as well as the registration of selectors), so
+/// it is safe to declare different classes from several threads at the same
+/// time.
+///
+/// Note however that this does not prevent declaring the same class twice;
+/// whichever thread is first will succeed, and [`ClassBuilder::new`] will
+/// return `None` on the others.
 #[derive(Debug)]
 pub struct ClassBuilder {
     // Note: Don't ever construct a &mut objc_class, since it is possible to
@@ -574,6 +586,8 @@ mod tests {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::Hash;
 
+    use alloc::vec::Vec;
+
     use memoffset::offset_of;
 
     use super::*;
@@ -762,6 +776,36 @@ mod tests {
         builder.add_protocol(protocol);
     }
 
+    #[test]
+    fn test_classbuilder_concurrent() {
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    let name = format!("TestClassBuilderConcurrent{i}");
+                    let mut builder = ClassBuilder::new(&name, NSObject::class()).unwrap();
+
+                    extern "C" fn get(_this: &NSObject, _cmd: Sel) -> u32 {
+                        42
+                    }
+                    let sel = Sel::register(&format!("concurrentMethod{i}"));
+                    unsafe { builder.add_method(sel, get as extern "C" fn(_, _) -> _) };
+
+                    let cls = builder.register();
+                    assert_eq!(cls.name(), name);
+                    assert!(cls.responds_to(sel));
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for i in 0..8 {
+            assert!(AnyClass::get(&format!("TestClassBuilderConcurrent{i}")).is_some());
+        }
+    }
+
     #[test]
     fn test_classbuilder_drop() {
         let cls = test_utils::custom_class();
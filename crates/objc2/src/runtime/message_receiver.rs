@@ -642,6 +642,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_send_message_super_stret() {
+        let obj = test_utils::custom_subclass_object();
+        let superclass = test_utils::custom_class();
+        let result: test_utils::CustomStruct =
+            unsafe { msg_send![super(&obj, superclass), customStruct] };
+        let expected = test_utils::CustomStruct {
+            a: 1,
+            b: 2,
+            c: 3,
+            d: 4,
+        };
+        assert_eq!(result, expected);
+    }
+
     #[test]
     #[cfg_attr(
         feature = "gnustep-1-7",
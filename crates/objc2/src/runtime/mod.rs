@@ -28,6 +28,7 @@ use std::os::raw::c_uint;
 // since `objc2-foundation` relies on it.
 #[doc(hidden)]
 pub mod __nsstring;
+mod associated_object;
 mod bool;
 mod declare;
 #[cfg(not(feature = "malloc"))]
@@ -40,6 +41,7 @@ mod nsproxy;
 mod nszone;
 mod protocol_object;
 mod retain_release_fast;
+mod synchronized;
 
 pub(crate) use self::method_encoding_iter::{EncodingParseError, MethodEncodingIter};
 pub(crate) use self::retain_release_fast::{objc_release_fast, objc_retain_fast};
@@ -52,6 +54,7 @@ use crate::{ffi, Message};
 #[doc(hidden)]
 pub use self::nsproxy::NSProxy as __NSProxy;
 
+pub use self::associated_object::{remove_associated_objects, AssociatedObject, AssociatedRef};
 pub use self::bool::Bool;
 pub use self::declare::{ClassBuilder, ProtocolBuilder};
 pub use self::message_receiver::MessageReceiver;
@@ -59,6 +62,7 @@ pub use self::method_implementation::MethodImplementation;
 pub use self::nsobject::{NSObject, NSObjectProtocol};
 pub use self::nszone::NSZone;
 pub use self::protocol_object::{ImplementedBy, ProtocolObject};
+pub use self::synchronized::{sync_lock, SyncGuard};
 pub use crate::verify::VerificationError;
 
 #[cfg(not(feature = "malloc"))]
@@ -1223,6 +1227,28 @@ impl AnyObject {
         unsafe { ptr.as_ref().unwrap_unchecked() }
     }
 
+    /// The name of the object's class.
+    ///
+    /// This is a shorthand for `obj.class().name()`, which is useful for
+    /// logging. Note that the actual class of an object is often a private
+    /// subclass of the class that you'd expect (for example, `NSString`s
+    /// are usually instances of `__NSCFString` or `NSTaggedPointerString`).
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::NSObject;
+    ///
+    /// let obj = NSObject::new();
+    /// assert_eq!(obj.class_name(), "NSObject");
+    /// ```
+    #[inline]
+    #[doc(alias = "object_getClassName")]
+    pub fn class_name(&self) -> &'static str {
+        self.class().name()
+    }
+
     /// Change the class of the object at runtime.
     ///
     /// Returns the object's previous class.
@@ -1322,10 +1348,6 @@ impl AnyObject {
         // SAFETY: Upheld by caller
         unsafe { ivar.load_mut::<T>(self) }
     }
-
-    // objc_setAssociatedObject
-    // objc_getAssociatedObject
-    // objc_removeAssociatedObjects
 }
 
 impl fmt::Debug for AnyObject {
@@ -1521,6 +1543,7 @@ mod tests {
         let mut obj = test_utils::custom_object();
         let cls = test_utils::custom_class();
         assert_eq!(obj.class(), cls);
+        assert_eq!(obj.class_name(), "CustomObject");
 
         let ivar = cls.instance_variable("_foo").unwrap();
 
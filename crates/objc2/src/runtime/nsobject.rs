@@ -108,6 +108,14 @@ pub unsafe trait NSObjectProtocol {
     /// [`PartialEq`] trait. If the objects you are comparing are of the same
     /// type, you likely want to use that instead.
     ///
+    /// Note that, as in Objective-C, this is not guaranteed to be symmetric
+    /// when comparing objects of different classes; `a.isEqual(b)` may
+    /// return `true` while `b.isEqual(a)` returns `false`, since each class
+    /// decides for itself what it considers equal.
+    ///
+    /// If two objects are equal, they must have the same
+    /// [`hash`][Self::hash].
+    ///
     /// See [Apple's documentation][apple-doc] for details.
     ///
     /// [apple-doc]: https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418795-isequal?language=objc
@@ -231,8 +239,55 @@ pub unsafe trait NSObjectProtocol {
         unsafe { msg_send![self, respondsToSelector: aSelector] }
     }
 
+    /// Send a message with the given selector to the object, passing the
+    /// given object as the argument.
+    ///
+    /// This is useful when the selector is only known at runtime. If the
+    /// selector is known at compile-time, you should use
+    /// [`msg_send_id!`][crate::msg_send_id] instead, which also supports
+    /// other argument and return types.
+    ///
+    /// See [Apple's documentation][apple-doc] for details.
+    ///
+    /// [apple-doc]: https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418764-performselector?language=objc
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The method that the selector refers to must take either zero or one
+    /// object arguments, and must return an object or `nil`. If the method
+    /// takes no arguments, `object` must be `None`.
+    ///
+    /// The selector must not be in the `new`, `alloc`, `init`, `copy` or
+    /// `mutableCopy` families, since the returned object is assumed not to
+    /// be owned by the caller.
+    ///
+    /// The method must be safe to call with the given argument, see
+    /// [`msg_send!`][crate::msg_send] for details.
+    #[doc(alias = "performSelector:")]
+    #[doc(alias = "performSelector:withObject:")]
+    unsafe fn performSelector_withObject(
+        &self,
+        aSelector: Sel,
+        object: Option<&AnyObject>,
+    ) -> Option<Id<AnyObject>>
+    where
+        Self: Sized + Message,
+    {
+        // SAFETY: `performSelector:withObject:` returns the result of the
+        // method, which the caller ensures is not retained.
+        unsafe { msg_send_id![self, performSelector: aSelector, withObject: object] }
+    }
+
     /// Check whether the object conforms to a given protocol.
     ///
+    /// Unlike [`AnyClass::conforms_to`], this is answered by the object
+    /// itself, so it also works for objects whose conformance differs from
+    /// that of their class, such as proxies.
+    ///
+    /// This can be used to check that an object implements a protocol
+    /// before converting it to a [`ProtocolObject`].
+    ///
     /// See [Apple's documentation][apple-doc] for details.
     ///
     /// [apple-doc]: https://developer.apple.com/documentation/objectivec/nsobject/1418893-conformstoprotocol?language=objc
@@ -480,9 +535,9 @@ mod tests {
     use super::*;
     use alloc::format;
 
-    use crate::extern_class;
-    use crate::mutability::Mutable;
+    use crate::mutability::{Immutable, Mutable};
     use crate::rc::__RcTestObject;
+    use crate::{declare_class, extern_class, extern_methods, msg_send_id, sel, DeclaredClass};
 
     extern_class!(
         #[derive(Debug, PartialEq, Eq, Hash)]
@@ -495,6 +550,13 @@ mod tests {
         }
     );
 
+    extern_methods!(
+        unsafe impl NSObjectMutable {
+            #[method(isEqual:)]
+            fn is_equal_nullable(&self, other: Option<&AnyObject>) -> bool;
+        }
+    );
+
     impl NSObjectMutable {
         fn new() -> Id<Self> {
             unsafe { Id::cast(NSObject::new()) }
@@ -551,6 +613,36 @@ mod tests {
         assert_ne!(obj1, obj2);
     }
 
+    #[test]
+    fn test_is_equal_hash_any_object() {
+        let obj1 = NSObject::new();
+        let obj2 = __RcTestObject::new();
+        let any1: &AnyObject = &obj1;
+        let any2: &AnyObject = &obj2;
+
+        assert!(obj1.isEqual(any1));
+        assert!(!obj1.isEqual(any2));
+        assert!(!obj2.isEqual(any1));
+
+        // The default hash is the address of the object
+        assert_ne!(obj1.hash(), obj2.hash());
+    }
+
+    #[test]
+    fn test_nullable_argument() {
+        let obj = NSObjectMutable::new();
+        let any: &AnyObject = &obj;
+
+        // `None` is passed as `nil`, which `isEqual:` allows.
+        let res: bool = unsafe { msg_send![&obj, isEqual: None::<&AnyObject>] };
+        assert!(!res);
+        let res: bool = unsafe { msg_send![&obj, isEqual: Some(any)] };
+        assert!(res);
+
+        assert!(!obj.is_equal_nullable(None));
+        assert!(obj.is_equal_nullable(Some(any)));
+    }
+
     #[test]
     fn test_hash() {
         use core::hash::Hasher;
@@ -580,6 +672,67 @@ mod tests {
         assert_eq!(format!("{obj:?}"), expected);
     }
 
+    #[test]
+    fn test_debug_nil_description() {
+        declare_class!(
+            struct NilDescription;
+
+            unsafe impl ClassType for NilDescription {
+                type Super = NSObject;
+                type Mutability = Immutable;
+                const NAME: &'static str = "__NSObjectTestsNilDescription";
+            }
+
+            impl DeclaredClass for NilDescription {}
+
+            unsafe impl NilDescription {
+                #[method_id(description)]
+                fn description(&self) -> Option<Id<NSObject>> {
+                    None
+                }
+            }
+        );
+
+        let obj: Id<NilDescription> = unsafe { msg_send_id![NilDescription::class(), new] };
+        let expected = format!("<__NSObjectTestsNilDescription: {:p}>", &*obj);
+        assert_eq!(format!("{:?}", &**obj), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_debug_recursive_description() {
+        use std::string::String;
+        use std::sync::Mutex;
+
+        static INNER: Mutex<Option<String>> = Mutex::new(None);
+
+        declare_class!(
+            struct RecursiveDescription;
+
+            unsafe impl ClassType for RecursiveDescription {
+                type Super = NSObject;
+                type Mutability = Immutable;
+                const NAME: &'static str = "__NSObjectTestsRecursiveDescription";
+            }
+
+            impl DeclaredClass for RecursiveDescription {}
+
+            unsafe impl RecursiveDescription {
+                #[method_id(description)]
+                fn description(&self) -> Id<NSObject> {
+                    *INNER.lock().unwrap() = Some(format!("{:?}", &**self));
+                    unsafe { msg_send_id![super(self), description] }
+                }
+            }
+        );
+
+        let obj: Id<RecursiveDescription> =
+            unsafe { msg_send_id![RecursiveDescription::class(), new] };
+        let expected = format!("<__NSObjectTestsRecursiveDescription: {:p}>", &*obj);
+        assert_eq!(format!("{:?}", &**obj), expected);
+        assert_eq!(INNER.lock().unwrap().as_deref(), Some(&*expected));
+    }
+
     #[test]
     fn test_is_kind_of() {
         let obj = NSObject::new();
@@ -591,6 +744,16 @@ mod tests {
         assert!(obj.is_kind_of::<__RcTestObject>());
     }
 
+    #[test]
+    fn test_responds_to_and_perform() {
+        let obj = NSObject::new();
+        assert!(obj.respondsToSelector(sel!(description)));
+        assert!(!obj.respondsToSelector(sel!(aMethodThatDoesNotExist)));
+
+        let this = unsafe { obj.performSelector_withObject(sel!(self), None) }.unwrap();
+        assert_eq!(Id::as_ptr(&this).cast::<NSObject>(), Id::as_ptr(&obj));
+    }
+
     #[test]
     fn test_retain_same() {
         let obj1 = NSObject::new();
@@ -606,6 +769,7 @@ mod tests {
     fn conforms_to_nsobjectprotocol() {
         let protocol = <dyn NSObjectProtocol>::protocol().unwrap();
         assert!(NSObject::class().conforms_to(protocol));
+        assert!(NSObject::new().conformsToProtocol(protocol));
     }
 
     // Ensure that importing `NSObjectProtocol::hash` does not cause conflicts
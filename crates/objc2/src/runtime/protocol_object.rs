@@ -1,3 +1,7 @@
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
 use core::fmt;
 use core::hash;
 use core::marker::PhantomData;
@@ -6,8 +10,8 @@ use core::ptr::NonNull;
 use crate::encode::{Encoding, RefEncode};
 use crate::rc::{autoreleasepool_leaking, Id};
 use crate::runtime::__nsstring::nsstring_to_str;
-use crate::runtime::{AnyObject, NSObjectProtocol};
-use crate::Message;
+use crate::runtime::{AnyObject, NSObject, NSObjectProtocol};
+use crate::{msg_send_id, Message};
 
 /// An internal helper trait for [`ProtocolObject`].
 ///
@@ -55,6 +59,22 @@ pub unsafe trait ImplementedBy<T: ?Sized + Message> {
 /// let proto: &ProtocolObject<dyn MyProtocol> = ProtocolObject::from_ref(&*obj);
 /// let proto: Id<ProtocolObject<dyn MyProtocol>> = ProtocolObject::from_id(obj);
 /// ```
+///
+/// A protocol object can also be upcast to a protocol object of one of the
+/// protocol's super-protocols, using the same methods (or [`AsRef`]).
+///
+/// ```
+/// use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+/// use objc2::rc::Id;
+/// # use objc2::runtime::NSObjectProtocol as MySubProtocol;
+///
+/// let obj = NSObject::new();
+/// let sub: Id<ProtocolObject<dyn MySubProtocol>> = ProtocolObject::from_id(obj);
+///
+/// let proto: &ProtocolObject<dyn NSObjectProtocol> = ProtocolObject::from_ref(&*sub);
+/// let proto: &ProtocolObject<dyn NSObjectProtocol> = (*sub).as_ref();
+/// let proto: Id<ProtocolObject<dyn NSObjectProtocol>> = ProtocolObject::from_id(sub);
+/// ```
 #[doc(alias = "id")]
 #[repr(C)]
 pub struct ProtocolObject<P: ?Sized> {
@@ -147,7 +167,22 @@ impl<P: ?Sized + NSObjectProtocol> hash::Hash for ProtocolObject<P> {
 
 impl<P: ?Sized + NSObjectProtocol> fmt::Debug for ProtocolObject<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let description = self.description();
+        // If the object's `description` ends up formatting the object itself
+        // (e.g. in a `declare_class!` that uses `Debug`), we would recurse
+        // indefinitely; so fall back to just printing the pointer instead.
+        let _guard = match RecursionGuard::new(&self.inner) {
+            Some(guard) => guard,
+            None => return fmt::Debug::fmt(&self.inner, f),
+        };
+
+        // SAFETY: `description` is always safe to call. The method is not
+        // supposed to return `nil`, but some classes do anyhow.
+        let description: Option<Id<NSObject>> = unsafe { msg_send_id![self, description] };
+        let description = match description {
+            Some(description) => description,
+            None => return fmt::Debug::fmt(&self.inner, f),
+        };
+
         // We use a leaking autorelease pool since often the string
         // will be UTF-8, and in that case the pool will be
         // irrelevant. Also, it allows us to pass the formatter into
@@ -162,6 +197,55 @@ impl<P: ?Sized + NSObjectProtocol> fmt::Debug for ProtocolObject<P> {
     }
 }
 
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The objects that are currently being formatted on this thread.
+    static FORMATTING: RefCell<Vec<*const AnyObject>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Tracks that an object is being formatted, to detect recursion.
+struct RecursionGuard {
+    #[cfg(feature = "std")]
+    obj: *const AnyObject,
+}
+
+impl RecursionGuard {
+    /// Returns `None` if the object is already being formatted.
+    #[inline]
+    fn new(_obj: &AnyObject) -> Option<Self> {
+        #[cfg(feature = "std")]
+        {
+            let obj: *const AnyObject = _obj;
+            FORMATTING.with(|objs| {
+                let mut objs = objs.borrow_mut();
+                if objs.contains(&obj) {
+                    None
+                } else {
+                    objs.push(obj);
+                    Some(Self { obj })
+                }
+            })
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Some(Self {})
+        }
+    }
+}
+
+impl Drop for RecursionGuard {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        FORMATTING.with(|objs| {
+            let mut objs = objs.borrow_mut();
+            if let Some(i) = objs.iter().rposition(|obj| *obj == self.obj) {
+                objs.remove(i);
+            }
+        });
+    }
+}
+
 impl<P: ?Sized, T> AsRef<ProtocolObject<T>> for ProtocolObject<P>
 where
     T: ?Sized + ImplementedBy<ProtocolObject<P>>,
@@ -354,6 +438,21 @@ mod tests {
         let _foobar: Id<ProtocolObject<dyn FooBar>> = ProtocolObject::from_id(obj);
     }
 
+    #[test]
+    fn upcast() {
+        let obj = DummyClass::new();
+        let ptr: *const DummyClass = &*obj;
+        let foobar: Id<ProtocolObject<dyn FooBar>> = ProtocolObject::from_id(obj);
+
+        let foo: &ProtocolObject<dyn Foo> = (*foobar).as_ref();
+        assert_eq!(foo as *const ProtocolObject<_>, ptr.cast());
+        let bar: &ProtocolObject<dyn Bar> = (*foobar).as_ref();
+        assert_eq!(bar as *const ProtocolObject<_>, ptr.cast());
+
+        let nsobject: Id<ProtocolObject<dyn NSObjectProtocol>> = ProtocolObject::from_id(foobar);
+        assert_eq!(Id::as_ptr(&nsobject), ptr.cast());
+    }
+
     #[test]
     fn test_traits() {
         use core::hash::Hasher;
@@ -0,0 +1,114 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::ffi;
+use crate::runtime::AnyObject;
+
+/// Lock the object's monitor, in the same way as `@synchronized` does.
+///
+/// The lock is released when the returned guard is dropped, including when
+/// unwinding from a panic.
+///
+/// The lock is recursive, so the same thread can lock the same object
+/// multiple times without deadlocking.
+///
+///
+/// # Panics
+///
+/// Panics if the runtime fails to lock the object.
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::runtime::{sync_lock, NSObject};
+///
+/// let obj = NSObject::new();
+/// {
+///     let _guard = sync_lock(&obj);
+///     // Equivalent to the body of `@synchronized (obj) { ... }`
+/// }
+/// ```
+#[doc(alias = "objc_sync_enter")]
+#[doc(alias = "@synchronized")]
+pub fn sync_lock(obj: &AnyObject) -> SyncGuard<'_> {
+    // SAFETY: The object is valid.
+    let res = unsafe { ffi::objc_sync_enter(obj.as_ptr() as *mut _) };
+    assert_eq!(res, 0, "failed locking object");
+    SyncGuard {
+        obj,
+        p: PhantomData,
+    }
+}
+
+/// A guard that holds an object's `@synchronized` lock.
+///
+/// This is created with [`sync_lock`], and unlocks the object when dropped.
+#[doc(alias = "objc_sync_exit")]
+#[must_use = "if unused the lock is released immediately"]
+pub struct SyncGuard<'a> {
+    obj: &'a AnyObject,
+    // The lock must be released on the thread that acquired it.
+    p: PhantomData<*const ()>,
+}
+
+impl Drop for SyncGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The object is valid, and was locked on this thread in
+        // `sync_lock`.
+        let res = unsafe { ffi::objc_sync_exit(self.obj.as_ptr() as *mut _) };
+        debug_assert_eq!(res, 0, "failed unlocking object");
+    }
+}
+
+impl fmt::Debug for SyncGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncGuard").field("obj", &self.obj).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::thread;
+
+    use super::*;
+    use crate::runtime::NSObject;
+
+    fn lock_on_other_thread(obj: &NSObject) {
+        // `NSObject` is thread-safe, it just isn't marked `Send` here.
+        let ptr = obj as *const NSObject as usize;
+        thread::spawn(move || {
+            // SAFETY: The object is kept alive until the thread is joined.
+            let obj = unsafe { &*(ptr as *const NSObject) };
+            let _guard = sync_lock(obj);
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_reentrant() {
+        let obj = NSObject::new();
+        let guard1 = sync_lock(&obj);
+        let guard2 = sync_lock(&obj);
+        drop(guard2);
+        drop(guard1);
+
+        // Would deadlock if the lock was still held.
+        lock_on_other_thread(&obj);
+    }
+
+    #[test]
+    fn test_unlocked_on_panic() {
+        let obj = NSObject::new();
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            let _guard = sync_lock(&obj);
+            panic!("test");
+        }));
+        assert!(res.is_err());
+
+        lock_on_other_thread(&obj);
+    }
+}
@@ -80,7 +80,18 @@ use crate::runtime::{AnyClass, AnyProtocol};
 /// //
 /// // And `Id<MyObject>` can now be constructed.
 /// ```
-pub unsafe trait Message: RefEncode {}
+pub unsafe trait Message: RefEncode {
+    /// The class that instances of this type are expected to be a kind of.
+    ///
+    /// Used to verify the return value of `msg_send_id!` when debug
+    /// assertions are enabled. Implemented by `extern_class!` and
+    /// `declare_class!`.
+    #[doc(hidden)]
+    #[inline]
+    fn __expected_class() -> Option<&'static AnyClass> {
+        None
+    }
+}
 
 /// Marks types that represent specific classes.
 ///
@@ -212,6 +223,13 @@ pub unsafe trait ClassType: Message {
     ///
     /// May register the class with the runtime if it wasn't already.
     ///
+    /// The implementations generated by [`extern_class!`] and
+    /// [`declare_class!`] cache the class after the first call, so calling
+    /// this repeatedly is cheap.
+    ///
+    /// [`extern_class!`]: crate::extern_class
+    /// [`declare_class!`]: crate::declare_class
+    ///
     ///
     /// # Panics
     ///
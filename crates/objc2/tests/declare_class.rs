@@ -4,7 +4,7 @@ use core::ptr::{self, NonNull};
 use objc2::mutability::Immutable;
 use objc2::rc::Id;
 use objc2::runtime::NSObject;
-use objc2::{declare_class, extern_methods, sel, ClassType, DeclaredClass};
+use objc2::{declare_class, extern_methods, msg_send, msg_send_id, sel, ClassType, DeclaredClass};
 
 // Test that adding the `deprecated` attribute does not mean that warnings
 // when using the method internally are output.
@@ -185,6 +185,67 @@ fn test_method_that_is_never_available() {
     assert!(!metacls.responds_to(sel!(never)));
 }
 
+declare_class!(
+    struct DeclareClassSuperBase;
+
+    unsafe impl ClassType for DeclareClassSuperBase {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassSuperBase";
+    }
+
+    impl DeclaredClass for DeclareClassSuperBase {}
+
+    unsafe impl DeclareClassSuperBase {
+        #[method(value)]
+        fn value(&self) -> i32 {
+            1
+        }
+    }
+);
+
+declare_class!(
+    struct DeclareClassSuperDerived;
+
+    unsafe impl ClassType for DeclareClassSuperDerived {
+        #[inherits(NSObject)]
+        type Super = DeclareClassSuperBase;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassSuperDerived";
+    }
+
+    impl DeclaredClass for DeclareClassSuperDerived {}
+
+    unsafe impl DeclareClassSuperDerived {
+        #[method(value)]
+        fn value(&self) -> i32 {
+            let value: i32 = unsafe { msg_send![super(self), value] };
+            value + 10
+        }
+
+        #[method_id(description)]
+        fn description(&self) -> Id<NSObject> {
+            unsafe { msg_send_id![super(self), description] }
+        }
+    }
+);
+
+#[test]
+fn test_call_super() {
+    let obj: Id<DeclareClassSuperDerived> =
+        unsafe { msg_send_id![DeclareClassSuperDerived::class(), new] };
+    let value: i32 = unsafe { msg_send![&obj, value] };
+    assert_eq!(value, 11);
+
+    // Calling a specific superclass skips the override.
+    let value: i32 = unsafe { msg_send![super(&*obj, DeclareClassSuperBase::class()), value] };
+    assert_eq!(value, 1);
+
+    let description: Id<NSObject> = unsafe { msg_send_id![&obj, description] };
+    let expected = format!("<DeclareClassSuperDerived: {:p}>", &*obj);
+    assert_eq!(format!("{description:?}"), expected);
+}
+
 declare_class!(
     struct TestMultipleColonSelector;
 
@@ -247,6 +308,10 @@ extern_methods!(
             arg3: i32,
             obj: *const Self,
         ) -> Option<Id<Self>>;
+
+        fn test_instance_sum(&self) -> i32 {
+            self.test_instance(1, 2, 3) + Self::test_class(4, 5, 6)
+        }
     }
 );
 
@@ -258,6 +323,7 @@ fn test_multiple_colon_selector() {
     assert_eq!(obj.test_instance(1, 2, 3), 6);
     assert!(obj.test_error(1, 2).is_ok());
     assert!(obj.test_object(1, 2, 3, ptr::null()).is_none());
+    assert_eq!(obj.test_instance_sum(), 21);
 }
 
 declare_class!(
@@ -463,6 +529,33 @@ fn out_param4() {
     OutParam::unsupported4(None);
 }
 
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic = "declared invalid method -[DeclareClassInvalidOverride hash]: expected return to have type code"]
+fn test_override_with_wrong_signature() {
+    declare_class!(
+        struct DeclareClassInvalidOverride;
+
+        unsafe impl ClassType for DeclareClassInvalidOverride {
+            type Super = NSObject;
+            type Mutability = Immutable;
+            const NAME: &'static str = "DeclareClassInvalidOverride";
+        }
+
+        impl DeclaredClass for DeclareClassInvalidOverride {}
+
+        unsafe impl DeclareClassInvalidOverride {
+            // `hash` returns `NSUInteger`
+            #[method(hash)]
+            fn hash(&self) -> i8 {
+                0
+            }
+        }
+    );
+
+    let _ = DeclareClassInvalidOverride::class();
+}
+
 #[test]
 fn test_pointer_receiver_allowed() {
     declare_class!(
@@ -0,0 +1,8 @@
+//! Test that the lifetime of `NSString::from_str_autoreleased` is bound to
+//! the pool.
+use objc2::rc::autoreleasepool;
+use objc2_foundation::NSString;
+
+fn main() {
+    let _s = autoreleasepool(|pool| NSString::from_str_autoreleased("foo", pool));
+}
@@ -11,6 +11,7 @@ typedef int32_t (^IntBlock)();
 typedef int32_t (^AddBlock)(int32_t);
 typedef int32_t (^Add12)(int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t);
 typedef LargeStruct (^LargeStructBlock)(LargeStruct);
+typedef id (^ObjectBlock)(void);
 
 
 IntBlock get_int_block() {
@@ -85,6 +86,12 @@ LargeStruct invoke_large_struct_block(LargeStructBlock block, LargeStruct s) {
 }
 
 
+id invoke_object_block(ObjectBlock block) {
+    // The returned object is autoreleased, so we must retain it to keep it
+    return [block() retain];
+}
+
+
 typedef int32_t (^ABlock)(void);
 
 void debug_block(void* block);
@@ -4,7 +4,7 @@ use std::thread_local;
 use alloc::string::ToString;
 use block2::{global_block, Block, RcBlock, StackBlock};
 use objc2::encode::{Encode, Encoding};
-use objc2::rc::Id;
+use objc2::rc::{__RcTestObject, __ThreadTestData, autoreleasepool, Id};
 use objc2::runtime::{AnyObject, Bool, NSObject};
 
 #[repr(C)]
@@ -77,6 +77,10 @@ extern "C" {
         s: LargeStruct,
     ) -> LargeStruct;
 
+    /// Invokes a block returning an autoreleased object, and returns the
+    /// object retained.
+    fn invoke_object_block(block: &Block<dyn Fn() -> *mut AnyObject>) -> *mut AnyObject;
+
     fn try_block_debugging(x: i32);
 }
 
@@ -208,6 +212,31 @@ fn test_large_struct_block() {
     assert_eq!(unsafe { invoke_large_struct_block(&block, data) }, new_data);
 }
 
+#[test]
+fn test_object_block() {
+    let expected = __ThreadTestData::current();
+
+    let block = RcBlock::new(|| {
+        let obj = Id::into_super(Id::into_super(__RcTestObject::new()));
+        Id::autorelease_return(obj)
+    });
+
+    autoreleasepool(|_| {
+        let obj = unsafe { invoke_object_block(&block) };
+        let obj = unsafe { Id::from_raw(obj) }.unwrap();
+        drop(obj);
+        // Still alive, since it was autoreleased when returned
+        assert_eq!(__ThreadTestData::current().drop, expected.drop);
+
+        let obj = unsafe { Id::retain_autoreleased(block.call(())) }.unwrap();
+        drop(obj);
+    });
+
+    let current = __ThreadTestData::current();
+    assert_eq!(current.alloc, expected.alloc + 2);
+    assert_eq!(current.drop, expected.drop + 2);
+}
+
 #[test]
 fn test_block_copy() {
     let s = "Hello!".to_string();
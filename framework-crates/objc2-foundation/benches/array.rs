@@ -0,0 +1,37 @@
+use iai::black_box;
+use objc2::rc::Id;
+use objc2_foundation::{NSArray, NSNumber};
+
+#[macro_use]
+#[path = "../../../crates/objc2/benches/common/mod.rs"]
+mod common;
+
+const LEN: usize = 1000;
+
+fn new_array() -> Id<NSArray<NSNumber>> {
+    NSArray::from_vec((0..LEN).map(NSNumber::new_usize).collect())
+}
+
+fn get_in_loop() -> usize {
+    let array = new_array();
+    let mut vec = Vec::with_capacity(LEN);
+    for i in 0..array.len() {
+        vec.push(array.get(i).unwrap());
+    }
+    black_box(&vec).len()
+}
+
+fn get_objects() -> usize {
+    let array = new_array();
+    let mut vec = Vec::with_capacity(LEN);
+    array.get_objects(&mut vec);
+    black_box(&vec).len()
+}
+
+main_with_warmup! {
+    // Baseline
+    new_array,
+    // Reading the whole array
+    get_in_loop,
+    get_objects,
+}
@@ -0,0 +1,59 @@
+use iai::black_box;
+use objc2_foundation::NSNumber;
+
+#[macro_use]
+#[path = "../../../crates/objc2/benches/common/mod.rs"]
+mod common;
+
+const LEN: i32 = 1000;
+
+fn new_i32() -> usize {
+    let mut vec = Vec::with_capacity(LEN as usize);
+    for _ in 0..10 {
+        vec.clear();
+        for i in 0..LEN {
+            vec.push(NSNumber::new_i32(i));
+        }
+    }
+    black_box(&vec).len()
+}
+
+fn new_i32_small() -> usize {
+    let mut vec = Vec::with_capacity(LEN as usize);
+    for _ in 0..10 {
+        vec.clear();
+        for i in 0..LEN {
+            vec.push(NSNumber::new_i32(i % 256));
+        }
+    }
+    black_box(&vec).len()
+}
+
+fn new_i32_cached() -> usize {
+    let mut vec = Vec::with_capacity(LEN as usize);
+    for _ in 0..10 {
+        vec.clear();
+        for i in 0..LEN {
+            vec.push(NSNumber::new_i32_cached(i));
+        }
+    }
+    black_box(&vec).len()
+}
+
+fn new_i32_small_cached() -> usize {
+    let mut vec = Vec::with_capacity(LEN as usize);
+    for _ in 0..10 {
+        vec.clear();
+        for i in 0..LEN {
+            vec.push(NSNumber::new_i32_cached(i % 256));
+        }
+    }
+    black_box(&vec).len()
+}
+
+main_with_warmup! {
+    new_i32,
+    new_i32_small,
+    new_i32_cached,
+    new_i32_small_cached,
+}
@@ -0,0 +1,33 @@
+use iai::black_box;
+use objc2::rc::autoreleasepool;
+use objc2_foundation::NSString;
+
+#[macro_use]
+#[path = "../../../crates/objc2/benches/common/mod.rs"]
+mod common;
+
+const LEN: usize = 1000;
+
+fn from_str() -> usize {
+    let mut vec = Vec::with_capacity(LEN);
+    for _ in 0..LEN {
+        vec.push(NSString::from_str("hello, world"));
+    }
+    black_box(&vec).len()
+}
+
+fn from_str_autoreleased() -> usize {
+    autoreleasepool(|pool| {
+        let mut vec = Vec::with_capacity(LEN);
+        for _ in 0..LEN {
+            vec.push(NSString::from_str_autoreleased("hello, world", pool));
+        }
+        black_box(&vec).len()
+    })
+}
+
+main_with_warmup! {
+    // Baseline
+    from_str,
+    from_str_autoreleased,
+}
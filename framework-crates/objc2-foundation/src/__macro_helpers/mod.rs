@@ -5,3 +5,5 @@ mod ns_string;
 pub use self::cached::CachedId;
 #[cfg(feature = "NSString")]
 pub use self::ns_string::*;
+#[cfg(feature = "alloc")]
+pub use alloc::format;
@@ -8,7 +8,7 @@ use core::ops::{Index, IndexMut};
 
 use objc2::mutability::{IsIdCloneable, IsMutable, IsRetainable};
 use objc2::rc::{Id, IdFromIterator};
-use objc2::{extern_methods, ClassType, Message};
+use objc2::{extern_methods, msg_send_id, ClassType, Message};
 
 #[cfg(feature = "NSEnumerator")]
 use super::iter;
@@ -84,6 +84,65 @@ impl<T: Message> NSArray<T> {
     // duplicate those.
 }
 
+#[cfg(feature = "NSString")]
+impl NSArray<crate::Foundation::NSString> {
+    /// Create a new array of strings from the given Rust string slices.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSArray;
+    ///
+    /// let array = NSArray::from_strings(&["a", "b", "c"]);
+    /// assert_eq!(array.to_vec_strings(), ["a", "b", "c"]);
+    /// ```
+    pub fn from_strings(strings: &[&str]) -> Id<Self> {
+        let vec = strings
+            .iter()
+            .map(|s| crate::Foundation::NSString::from_str(s))
+            .collect();
+        Self::from_vec(vec)
+    }
+
+    /// Convert each string in the array to a Rust [`String`].
+    ///
+    /// This is more efficient than converting each element separately,
+    /// since all the conversions happen inside a single autorelease pool.
+    ///
+    /// [`String`]: alloc::string::String
+    pub fn to_vec_strings(&self) -> Vec<alloc::string::String> {
+        objc2::rc::autoreleasepool(|pool| {
+            (0..self.len())
+                .map(|i| self[i].as_str(pool).into())
+                .collect()
+        })
+    }
+
+    /// Join the strings in the array, with `separator` between each of them.
+    ///
+    /// An empty array results in an empty string.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray};
+    ///
+    /// let array = NSArray::from_strings(&["a", "b", "c"]);
+    /// assert_eq!(array.joined(ns_string!(", ")).to_string(), "a, b, c");
+    /// ```
+    #[doc(alias = "componentsJoinedByString:")]
+    pub fn joined(
+        &self,
+        separator: &crate::Foundation::NSString,
+    ) -> Id<crate::Foundation::NSString> {
+        // SAFETY: The separator is a valid string, and the method returns a
+        // new string.
+        unsafe { msg_send_id![self, componentsJoinedByString: separator] }
+    }
+}
+
 impl<T: Message> NSMutableArray<T> {
     pub fn from_vec(mut vec: Vec<Id<T>>) -> Id<Self> {
         let len = vec.len();
@@ -133,6 +192,85 @@ impl<T: Message> NSArray<T> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Whether the array contains an object equal to `obj`.
+    ///
+    /// Objects are compared using their `isEqual:` method.
+    #[doc(alias = "containsObject:")]
+    pub fn contains(&self, obj: &T) -> bool {
+        // SAFETY: The object is not retained by the array.
+        unsafe { self.containsObject(obj) }
+    }
+
+    /// The index of the first object in the array that is equal to `obj`, or
+    /// [`None`] if no such object exists.
+    ///
+    /// Objects are compared using their `isEqual:` method.
+    #[doc(alias = "indexOfObject:")]
+    pub fn index_of(&self, obj: &T) -> Option<usize> {
+        // SAFETY: The object is not retained by the array.
+        let index = unsafe { self.indexOfObject(obj) };
+        util::not_found_to_none(index)
+    }
+
+    /// Returns a new array with the objects sorted by the given comparison
+    /// closure.
+    ///
+    /// See [`ComparatorBlock`] for details on how the closure is called.
+    ///
+    /// [`ComparatorBlock`]: crate::Foundation::ComparatorBlock
+    #[doc(alias = "sortedArrayUsingComparator:")]
+    #[cfg(feature = "NSObjCRuntime")]
+    #[cfg(feature = "block2")]
+    pub fn sorted_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&self, compare: F) -> Id<Self>
+    where
+        T: IsRetainable,
+    {
+        let comparator = crate::Foundation::ComparatorBlock::new(compare);
+        // SAFETY: The comparator has the correct signature, and compares
+        // objects of type `T`. The new array retains the objects, which is
+        // fine because of the `T: IsRetainable` bound.
+        unsafe { msg_send_id![self, sortedArrayUsingComparator: comparator.as_block()] }
+    }
+
+    /// The index at which `needle` can be inserted while keeping the array
+    /// sorted according to `compare`.
+    ///
+    /// The array is assumed to already be sorted, and a binary search is
+    /// done over it, similar to [`slice::partition_point`]. If the array
+    /// contains objects that compare equal to `needle`, the returned index
+    /// is after all of them.
+    ///
+    /// The result can be passed to [`NSMutableArray::insert`].
+    pub fn insertion_index<F: FnMut(&T, &T) -> core::cmp::Ordering>(
+        &self,
+        needle: &T,
+        mut compare: F,
+    ) -> usize {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if compare(&self[mid], needle).is_gt() {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        low
+    }
+
+    /// Whether the array contains the same objects as `other`, in the same
+    /// order.
+    ///
+    /// Objects are compared using their [`PartialEq`] implementation, which
+    /// usually uses `isEqual:`.
+    pub fn eq_slice(&self, other: &[Id<T>]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.len() == other.len() && other.iter().enumerate().all(|(i, obj)| self[i] == **obj)
+    }
 }
 
 extern_methods!(
@@ -243,6 +381,257 @@ impl<T: Message> NSArray<T> {
         // SAFETY: Just checked that the range is in bounds
         Some(unsafe { self.objects_in_range_unchecked(range) })
     }
+
+    /// Replace the contents of `out` with the objects in the array.
+    ///
+    /// This fetches all the objects with a single message send, which is
+    /// much faster than calling [`get`][Self::get] in a loop. The allocation
+    /// in `out` is reused, so this is also useful when reading several
+    /// arrays in a row.
+    #[doc(alias = "getObjects:range:")]
+    #[cfg(feature = "NSRange")]
+    pub fn get_objects<'a>(&'a self, out: &mut Vec<&'a T>) {
+        let len = self.len();
+        out.clear();
+        out.reserve(len);
+        let range = crate::Foundation::NSRange::from(0..len);
+        let ptr: *mut &'a T = out.as_mut_ptr();
+        // SAFETY: `&T` has the same layout as `NonNull<T>`, the range is in
+        // bounds, and `out` has capacity for at least `len` elements.
+        //
+        // The objects are valid for as long as the array is borrowed.
+        unsafe {
+            self.getObjects_range(core::ptr::NonNull::new(ptr.cast()).unwrap(), range);
+            out.set_len(len);
+        }
+    }
+
+    /// Create a new array containing the objects in the given range.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[doc(alias = "subarrayWithRange:")]
+    #[cfg(feature = "NSRange")]
+    pub fn subarray(&self, range: crate::Foundation::NSRange) -> Id<NSArray<T>>
+    where
+        T: IsIdCloneable,
+    {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        assert!(
+            range.end() <= self.len(),
+            "range {range:?} out of bounds of array with length {}",
+            self.len(),
+        );
+        // SAFETY: The range is checked to be in bounds, and the new array
+        // retains the objects, which is allowed because of the
+        // `T: IsIdCloneable` bound.
+        unsafe { msg_send_id![self, subarrayWithRange: range] }
+    }
+
+    /// Create a new array containing the objects in this array, followed by
+    /// the objects in `other`.
+    #[doc(alias = "arrayByAddingObjectsFromArray:")]
+    pub fn concat(&self, other: &NSArray<T>) -> Id<NSArray<T>>
+    where
+        T: IsIdCloneable,
+    {
+        // SAFETY: The new array retains the objects, which is allowed
+        // because of the `T: IsIdCloneable` bound.
+        unsafe { msg_send_id![self, arrayByAddingObjectsFromArray: other] }
+    }
+
+    /// Create a new array by calling the closure on each object in the
+    /// array, in order.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, NSNumber};
+    ///
+    /// let array = NSArray::from_vec(vec![NSNumber::new_i32(1), NSNumber::new_i32(2)]);
+    /// let doubled = array.map(|n| NSNumber::new_i32(n.as_i32() * 2));
+    /// assert_eq!(doubled[0].as_i32(), 2);
+    /// assert_eq!(doubled[1].as_i32(), 4);
+    /// ```
+    #[cfg(feature = "NSEnumerator")]
+    pub fn map<U: Message, F: FnMut(&T) -> Id<U>>(&self, f: F) -> Id<NSArray<U>> {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.extend(self.iter().map(f));
+        NSArray::from_vec(vec)
+    }
+
+    /// Create a new array containing the objects for which the closure
+    /// returns `true`, in order.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, NSNumber};
+    ///
+    /// let array = NSArray::from_vec(vec![NSNumber::new_i32(1), NSNumber::new_i32(2)]);
+    /// let even = array.filter(|n| n.as_i32() % 2 == 0);
+    /// assert_eq!(even.len(), 1);
+    /// assert_eq!(even[0].as_i32(), 2);
+    /// ```
+    #[cfg(feature = "NSEnumerator")]
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut f: F) -> Id<NSArray<T>>
+    where
+        T: IsRetainable,
+    {
+        let vec: Vec<&T> = self.iter().filter(|obj| f(obj)).collect();
+        // The new array retains each of the matching objects once.
+        NSArray::from_slice(&vec)
+    }
+
+    /// Call the given closure with each object in the array, until it
+    /// returns [`ControlFlow::Break`].
+    ///
+    /// Returns [`ControlFlow::Break`] if the enumeration was stopped early.
+    ///
+    /// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Unwinding out of a block into Objective-C is not allowed, so the
+    /// process is aborted if the closure panics.
+    #[doc(alias = "enumerateObjectsUsingBlock:")]
+    #[cfg(feature = "block2")]
+    pub fn try_for_each<F: FnMut(&T) -> core::ops::ControlFlow<()>>(
+        &self,
+        f: F,
+    ) -> core::ops::ControlFlow<()> {
+        use core::cell::{Cell, RefCell};
+        use core::ops::ControlFlow;
+        use core::ptr::NonNull;
+
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        let f = RefCell::new(f);
+        let stopped = Cell::new(false);
+        let block = StackBlock::new(|obj: NonNull<T>, _index: usize, mut stop: NonNull<Bool>| {
+            let guard = util::AbortOnUnwind;
+            // SAFETY: The object is stored in the array, which is
+            // borrowed for the duration of the enumeration.
+            let obj = unsafe { obj.as_ref() };
+            if (f.borrow_mut())(obj).is_break() {
+                stopped.set(true);
+                // SAFETY: The stop pointer is valid for writes.
+                unsafe { *stop.as_mut() = Bool::YES };
+            }
+            core::mem::forget(guard);
+        });
+        // SAFETY: The block has the correct signature, and is only used for
+        // the duration of the call.
+        //
+        // The array cannot be mutated during the enumeration, since we hold
+        // a shared reference to it.
+        let _: () = unsafe { objc2::msg_send![self, enumerateObjectsUsingBlock: &*block] };
+
+        if stopped.get() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// The indexes of the objects for which the given closure returns
+    /// `true`.
+    ///
+    /// This can be combined with e.g. `removeObjectsAtIndexes:` to remove
+    /// several objects at once.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Unwinding out of a block into Objective-C is not allowed, so the
+    /// process is aborted if the closure panics.
+    #[doc(alias = "indexesOfObjectsPassingTest:")]
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSIndexSet")]
+    pub fn indexes_passing<F: FnMut(&T) -> bool>(&self, f: F) -> Id<crate::Foundation::NSIndexSet> {
+        use core::cell::RefCell;
+        use core::ptr::NonNull;
+
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        let f = RefCell::new(f);
+        let block = StackBlock::new(|obj: NonNull<T>, _index: usize, _stop: NonNull<Bool>| {
+            let guard = util::AbortOnUnwind;
+            // SAFETY: The object is stored in the array, which is
+            // borrowed for the duration of the enumeration.
+            let obj = unsafe { obj.as_ref() };
+            let passed = (f.borrow_mut())(obj);
+            core::mem::forget(guard);
+            Bool::new(passed)
+        });
+        // SAFETY: The block has the correct signature, and is only used for
+        // the duration of the call.
+        //
+        // The array cannot be mutated during the enumeration, since we hold
+        // a shared reference to it.
+        unsafe { msg_send_id![self, indexesOfObjectsPassingTest: &*block] }
+    }
+
+    /// Call the given closure with each object in the array, possibly
+    /// concurrently from multiple threads.
+    ///
+    /// The objects are visited in no particular order. Since Foundation may
+    /// invoke the closure from several threads at once, both the closure
+    /// and the objects must be thread-safe, as enforced by the [`Sync`]
+    /// bounds.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// The process is aborted if the closure panics, since unwinding out of
+    /// Foundation's worker threads is not allowed.
+    #[doc(alias = "enumerateObjectsWithOptions:usingBlock:")]
+    #[doc(alias = "NSEnumerationConcurrent")]
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSObjCRuntime")]
+    pub fn par_for_each<F: Fn(&T) + Sync>(&self, f: F)
+    where
+        T: Sync,
+    {
+        use core::ptr::NonNull;
+
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        use crate::Foundation::NSEnumerationOptions;
+
+        let f = &f;
+        let block = StackBlock::new(
+            move |obj: NonNull<T>, _index: usize, _stop: NonNull<Bool>| {
+                let guard = util::AbortOnUnwind;
+                // SAFETY: The object is stored in the array, which is
+                // borrowed for the duration of the enumeration.
+                let obj = unsafe { obj.as_ref() };
+                f(obj);
+                core::mem::forget(guard);
+            },
+        );
+        // SAFETY: The block has the correct signature, and the enumeration
+        // has finished when the method returns, so the block does not
+        // outlive the call.
+        //
+        // The closure and the objects are `Sync`, so it is safe to use them
+        // from multiple threads at once.
+        let _: () = unsafe {
+            objc2::msg_send![
+                self,
+                enumerateObjectsWithOptions: NSEnumerationOptions::NSEnumerationConcurrent,
+                usingBlock: &*block,
+            ]
+        };
+    }
 }
 
 impl<T: Message> NSMutableArray<T> {
@@ -256,7 +645,7 @@ impl<T: Message> NSMutableArray<T> {
     pub fn insert(&mut self, index: usize, obj: Id<T>) {
         // TODO: Replace this check with catching the thrown NSRangeException
         let len = self.len();
-        if index < len {
+        if index <= len {
             // SAFETY: We've consumed ownership of the object, and the
             // index is checked to be in bounds.
             unsafe { self.insertObject_atIndex(&obj, index) }
@@ -334,6 +723,65 @@ impl<T: Message> NSMutableArray<T> {
         // Keep the closure alive until the function has run.
         drop(closure);
     }
+
+    /// Removes all but the first of consecutive objects that `same` considers
+    /// to be equal.
+    ///
+    /// Like [`Vec::dedup_by`], `same` is passed the current object and the
+    /// last object that was kept. If the array is sorted, this removes all
+    /// duplicates.
+    ///
+    /// [`Vec::dedup_by`]: alloc::vec::Vec::dedup_by
+    #[doc(alias = "removeObjectsAtIndexes:")]
+    #[cfg(feature = "NSIndexSet")]
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) {
+        use crate::Foundation::NSMutableIndexSet;
+
+        // Find all the objects to remove before mutating the array, to avoid
+        // calling `same` while the array is in an intermediate state.
+        let to_remove: Id<NSMutableIndexSet> =
+            unsafe { msg_send_id![NSMutableIndexSet::class(), new] };
+        let mut kept = 0;
+        for index in 1..self.len() {
+            // SAFETY: Both indices are in bounds.
+            let (obj, kept_obj) = unsafe { (self.get_unchecked(index), self.get_unchecked(kept)) };
+            if same(obj, kept_obj) {
+                // SAFETY: The index set is owned by us.
+                let _: () = unsafe { objc2::msg_send![&to_remove, addIndex: index] };
+            } else {
+                kept = index;
+            }
+        }
+
+        // Remove all the duplicates at once, instead of shifting the
+        // remaining objects once for every removed object.
+        //
+        // SAFETY: The indexes are in bounds, and the removed objects are
+        // released by the array.
+        let _: () = unsafe { objc2::msg_send![self, removeObjectsAtIndexes: &*to_remove] };
+    }
+
+    /// Removes consecutive repeated objects.
+    ///
+    /// Objects are compared using their [`PartialEq`] implementation, which
+    /// usually uses `isEqual:`.
+    #[cfg(feature = "NSIndexSet")]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Reverses the order of the objects in the array, in place.
+    #[doc(alias = "exchangeObjectAtIndex:withObjectAtIndex:")]
+    pub fn reverse(&mut self) {
+        let len = self.len();
+        for index in 0..len / 2 {
+            // SAFETY: Both indices are in bounds.
+            unsafe { self.exchangeObjectAtIndex_withObjectAtIndex(index, len - index - 1) };
+        }
+    }
 }
 
 impl<T: Message> NSArray<T> {
@@ -468,6 +916,20 @@ impl<T: Message> Index<usize> for NSMutableArray<T> {
     }
 }
 
+impl<T: Message + PartialEq> PartialEq<[Id<T>]> for NSArray<T> {
+    #[inline]
+    fn eq(&self, other: &[Id<T>]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<T: Message + PartialEq> PartialEq<[Id<T>]> for NSMutableArray<T> {
+    #[inline]
+    fn eq(&self, other: &[Id<T>]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
 impl<T: Message + IsMutable> IndexMut<usize> for NSArray<T> {
     fn index_mut(&mut self, index: usize) -> &mut T {
         self.get_mut(index).unwrap()
@@ -482,9 +944,19 @@ impl<T: Message + IsMutable> IndexMut<usize> for NSMutableArray<T> {
 
 #[cfg(feature = "NSEnumerator")]
 impl<T: fmt::Debug + Message> fmt::Debug for NSArray<T> {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self).finish()
+        // Allow limiting the number of elements that are printed with the
+        // precision, e.g. `{:.10?}`, which is useful for large arrays.
+        if let Some(limit) = f.precision() {
+            let mut list = f.debug_list();
+            list.entries(self.iter().take(limit).map(util::DebugWithoutPrecision));
+            if self.len() > limit {
+                list.entry(&format_args!("..."));
+            }
+            list.finish()
+        } else {
+            f.debug_list().entries(self).finish()
+        }
     }
 }
 
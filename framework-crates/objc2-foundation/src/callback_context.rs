@@ -0,0 +1,166 @@
+//! Utilities for passing Rust closures to C callbacks.
+use alloc::boxed::Box;
+use core::fmt;
+use core::mem;
+use std::os::raw::c_void;
+
+use crate::util::AbortOnUnwind;
+
+/// A boxed closure that can be passed as the `void *context` parameter of a
+/// C callback.
+///
+/// Many Objective-C and Core Foundation APIs take a function pointer along
+/// with an opaque context pointer, which is passed back to the function when
+/// it is called. This type helps with storing a Rust closure in that context
+/// pointer.
+///
+/// If the callback only runs while the API call is in progress, use
+/// [`as_ptr`][Self::as_ptr], and keep the context alive until the call has
+/// returned. If the callback may run later, use
+/// [`into_raw`][Self::into_raw] and [`from_raw`][Self::from_raw] to transfer
+/// ownership of the closure, so that it is not leaked.
+///
+/// The functions returned by [`trampoline`][Self::trampoline] and
+/// [`trampoline_once`][Self::trampoline_once] can be used directly for
+/// callbacks with the signature `void (*)(void *context)`. For other
+/// signatures, write a custom `extern "C" fn` that uses
+/// [`closure_from_raw`][Self::closure_from_raw].
+///
+///
+/// # Panics
+///
+/// Unwinding out of a callback into C is not allowed, so the trampolines
+/// abort the process if the closure panics.
+///
+///
+/// # Examples
+///
+/// ```
+/// use std::os::raw::c_void;
+/// use objc2_foundation::CallbackContext;
+///
+/// // A C function that calls the callback twice.
+/// unsafe extern "C" fn call_twice(f: unsafe extern "C" fn(*mut c_void), context: *mut c_void) {
+///     unsafe { f(context) };
+///     unsafe { f(context) };
+/// }
+///
+/// let mut count = 0;
+/// let mut context = CallbackContext::new(|| count += 1);
+/// unsafe { call_twice(context.trampoline(), context.as_ptr()) };
+/// drop(context);
+/// assert_eq!(count, 2);
+/// ```
+pub struct CallbackContext<F> {
+    closure: Box<F>,
+}
+
+impl<F> CallbackContext<F> {
+    /// Box the given closure.
+    #[inline]
+    pub fn new(closure: F) -> Self {
+        Self {
+            closure: Box::new(closure),
+        }
+    }
+
+    /// A pointer to the closure, for passing as the context parameter.
+    ///
+    /// The pointer is only valid for as long as `self` is.
+    #[inline]
+    pub fn as_ptr(&mut self) -> *mut c_void {
+        let ptr: *mut F = &mut *self.closure;
+        ptr.cast()
+    }
+
+    /// Consume the context, and return a pointer that owns the closure.
+    ///
+    /// Use [`from_raw`][Self::from_raw] to drop the closure afterwards,
+    /// otherwise it is leaked.
+    #[inline]
+    pub fn into_raw(self) -> *mut c_void {
+        Box::into_raw(self.closure).cast()
+    }
+
+    /// Take back ownership of a closure that was passed to
+    /// [`into_raw`][Self::into_raw].
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been created by `into_raw` on a context with
+    /// the same closure type `F`, and must not be used afterwards.
+    #[inline]
+    pub unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        Self {
+            // SAFETY: Upheld by the caller.
+            closure: unsafe { Box::from_raw(ptr.cast()) },
+        }
+    }
+
+    /// Get a reference to the closure behind a context pointer.
+    ///
+    /// This is useful when implementing a trampoline for a custom callback
+    /// signature.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been created by [`as_ptr`][Self::as_ptr] or
+    /// [`into_raw`][Self::into_raw] on a context with the same closure type
+    /// `F`, which must still be alive for the lifetime `'a`. The closure must
+    /// not be accessed from elsewhere during that time.
+    #[inline]
+    pub unsafe fn closure_from_raw<'a>(ptr: *mut c_void) -> &'a mut F {
+        // SAFETY: Upheld by the caller.
+        unsafe { &mut *ptr.cast::<F>() }
+    }
+}
+
+impl<F: FnMut()> CallbackContext<F> {
+    /// A function that calls the closure behind the context pointer.
+    ///
+    /// The closure is not dropped, so the function can be called multiple
+    /// times.
+    ///
+    /// Calling the function is unsafe; the context pointer must be valid as
+    /// described in [`closure_from_raw`][Self::closure_from_raw].
+    pub fn trampoline(&self) -> unsafe extern "C" fn(*mut c_void) {
+        unsafe extern "C" fn call<F: FnMut()>(context: *mut c_void) {
+            let guard = AbortOnUnwind;
+            // SAFETY: Upheld by the caller.
+            let closure = unsafe { CallbackContext::<F>::closure_from_raw(context) };
+            closure();
+            mem::forget(guard);
+        }
+
+        call::<F>
+    }
+}
+
+impl<F: FnOnce()> CallbackContext<F> {
+    /// A function that calls and drops the closure behind the context
+    /// pointer.
+    ///
+    /// Calling the function is unsafe; the context pointer must have been
+    /// created with [`into_raw`][Self::into_raw], and the function must be
+    /// called at most once.
+    pub fn trampoline_once(&self) -> unsafe extern "C" fn(*mut c_void) {
+        unsafe extern "C" fn call_once<F: FnOnce()>(context: *mut c_void) {
+            let guard = AbortOnUnwind;
+            // SAFETY: Upheld by the caller.
+            let this = unsafe { CallbackContext::<F>::from_raw(context) };
+            let closure = *this.closure;
+            closure();
+            mem::forget(guard);
+        }
+
+        call_once::<F>
+    }
+}
+
+impl<F> fmt::Debug for CallbackContext<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackContext").finish_non_exhaustive()
+    }
+}
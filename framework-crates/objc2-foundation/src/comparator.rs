@@ -0,0 +1,100 @@
+//! Utilities for creating `NSComparator` blocks.
+use core::cell::RefCell;
+use core::cmp::Ordering;
+use core::fmt;
+use core::mem;
+use core::ptr::NonNull;
+
+use block2::{Block, RcBlock};
+use objc2::Message;
+
+use crate::util::AbortOnUnwind;
+use crate::Foundation::NSComparisonResult;
+
+type ComparatorFn<'f, T> = dyn Fn(NonNull<T>, NonNull<T>) -> NSComparisonResult + 'f;
+
+/// A comparator block created from a Rust closure.
+///
+/// This is the Rust equivalent of `NSComparator`, and can be passed to
+/// methods like `sortedArrayUsingComparator:` that take a block comparing
+/// two objects. The [`Ordering`] returned by the closure is converted to the
+/// corresponding [`NSComparisonResult`].
+///
+/// The block is reference-counted, so it stays alive for as long as either
+/// this value or Objective-C holds on to it.
+///
+///
+/// # Panics
+///
+/// Unwinding out of a block into Objective-C is not allowed, so the process
+/// is aborted if the closure panics.
+///
+/// This also happens if the block is called re-entrantly from the same
+/// thread.
+///
+///
+/// # Safety
+///
+/// The closure is not required to be thread-safe, so the block must only be
+/// called from one thread at a time. In particular, it must not be used with
+/// `NSSortConcurrent`; the block would then be called from several threads
+/// at once, which is a data race and thus undefined behaviour (it is _not_
+/// guaranteed to abort).
+///
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "NSArray", feature = "NSValue"), doc = "```")]
+#[cfg_attr(not(all(feature = "NSArray", feature = "NSValue")), doc = "```ignore")]
+/// use objc2::msg_send_id;
+/// use objc2::rc::Id;
+/// use objc2_foundation::{ComparatorBlock, NSArray, NSNumber};
+///
+/// let array = NSArray::from_vec(vec![NSNumber::new_i32(2), NSNumber::new_i32(1)]);
+/// let comparator = ComparatorBlock::new(|a: &NSNumber, b: &NSNumber| a.as_i32().cmp(&b.as_i32()));
+/// let sorted: Id<NSArray<NSNumber>> = unsafe {
+///     msg_send_id![&array, sortedArrayUsingComparator: comparator.as_block()]
+/// };
+/// assert_eq!(sorted[0].as_i32(), 1);
+/// ```
+pub struct ComparatorBlock<'f, T: Message> {
+    block: RcBlock<ComparatorFn<'f, T>>,
+}
+
+impl<'f, T: Message + 'f> ComparatorBlock<'f, T> {
+    /// Create a new comparator block from the given closure.
+    pub fn new<F: FnMut(&T, &T) -> Ordering + 'f>(compare: F) -> Self {
+        let compare = RefCell::new(compare);
+        let block = RcBlock::new(move |obj1: NonNull<T>, obj2: NonNull<T>| {
+            let guard = AbortOnUnwind;
+            // SAFETY: The objects are guaranteed to be valid while they are
+            // being compared.
+            let (obj1, obj2) = unsafe { (obj1.as_ref(), obj2.as_ref()) };
+            let ordering = (compare.borrow_mut())(obj1, obj2);
+            mem::forget(guard);
+            NSComparisonResult::from(ordering)
+        });
+        Self { block }
+    }
+
+    /// The underlying block, for passing to Objective-C.
+    #[inline]
+    pub fn as_block(&self) -> &Block<ComparatorFn<'f, T>> {
+        &self.block
+    }
+}
+
+impl<T: Message> Clone for ComparatorBlock<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            block: self.block.clone(),
+        }
+    }
+}
+
+impl<T: Message> fmt::Debug for ComparatorBlock<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComparatorBlock").finish_non_exhaustive()
+    }
+}
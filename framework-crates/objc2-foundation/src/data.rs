@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 #[cfg(feature = "block2")]
 use alloc::vec::Vec;
 use core::ffi::c_void;
@@ -14,8 +16,12 @@ use objc2::rc::Id;
 #[cfg(feature = "block2")]
 use objc2::rc::IdFromIterator;
 use objc2::{extern_methods, ClassType};
+#[cfg(feature = "NSString")]
+use objc2::{msg_send, msg_send_id};
 
 use crate::Foundation::{NSData, NSMutableData};
+#[cfg(feature = "NSString")]
+use crate::Foundation::{NSString, NSUInteger};
 
 // SAFETY: `NSData` is immutable and `NSMutableData` can only be mutated from
 // `&mut` methods.
@@ -98,6 +104,82 @@ impl NSData {
     }
 }
 
+#[cfg(feature = "NSString")]
+impl NSData {
+    /// Decode a Base64-encoded string.
+    ///
+    /// Returns [`None`] if the string is not valid Base64.
+    #[doc(alias = "initWithBase64EncodedString:options:")]
+    pub fn from_base64(string: &NSString) -> Option<Id<Self>> {
+        // SAFETY: The string is valid, and no decoding options are given.
+        // The method returns `nil` if the input is not valid Base64.
+        unsafe {
+            msg_send_id![
+                Self::alloc(),
+                initWithBase64EncodedString: string,
+                options: 0 as NSUInteger,
+            ]
+        }
+    }
+
+    /// Encode the data as a Base64 string.
+    #[doc(alias = "base64EncodedStringWithOptions:")]
+    pub fn to_base64(&self) -> Id<NSString> {
+        // SAFETY: No encoding options are given, which means that the
+        // string is not split into lines.
+        unsafe { msg_send_id![self, base64EncodedStringWithOptions: 0 as NSUInteger] }
+    }
+
+    /// Read the contents of the file at the given path.
+    ///
+    /// Returns [`None`] if the file could not be read, for example because
+    /// it does not exist or because of missing permissions.
+    ///
+    /// Use [`NSString::from_path`] to convert a [`std::path::Path`].
+    #[doc(alias = "dataWithContentsOfFile:")]
+    pub fn from_contents_of_file(path: &NSString) -> Option<Id<Self>> {
+        // SAFETY: The path is a valid string, and the method returns `nil`
+        // instead of throwing if the file could not be read.
+        unsafe { msg_send_id![Self::class(), dataWithContentsOfFile: path] }
+    }
+
+    /// Write the data to the file at the given path.
+    ///
+    /// If `atomically` is `true`, the data is first written to a temporary
+    /// file, which is then renamed to the given path.
+    ///
+    /// Returns `false` if the file could not be written.
+    #[doc(alias = "writeToFile:atomically:")]
+    pub fn write_to_file(&self, path: &NSString, atomically: bool) -> bool {
+        // SAFETY: The path is a valid string, and the method returns `NO`
+        // instead of throwing if the file could not be written.
+        unsafe { msg_send![self, writeToFile: path, atomically: atomically] }
+    }
+}
+
+impl NSData {
+    /// Format the data as a string of lowercase hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSData;
+    ///
+    /// let data = NSData::with_bytes(&[0x01, 0xab, 0xff]);
+    /// assert_eq!(data.to_hex_string(), "01abff");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_string(&self) -> String {
+        use core::fmt::Write;
+
+        let mut string = String::with_capacity(self.len() * 2);
+        for byte in self.bytes() {
+            write!(string, "{byte:02x}").unwrap();
+        }
+        string
+    }
+}
+
 impl NSMutableData {
     #[doc(alias = "mutableBytes")]
     pub fn bytes_mut(&mut self) -> &mut [u8] {
@@ -121,11 +203,30 @@ impl NSMutableData {
         self.extend_from_slice(&[byte])
     }
 
+    /// Append the contents of another data object to this one.
+    #[doc(alias = "appendData:")]
+    pub fn append_data(&mut self, other: &NSData) {
+        // SAFETY: `other` is a valid `NSData`, and it is copied, so it is
+        // fine if it aliases `self`.
+        unsafe { self.appendData(other) }
+    }
+
+    /// Replace the bytes in the given range with the given bytes.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
     #[doc(alias = "replaceBytesInRange:withBytes:length:")]
     #[cfg(feature = "NSRange")]
     pub fn replace_range(&mut self, range: Range<usize>, bytes: &[u8]) {
-        // No need to verify the length of the range here,
-        // `replaceBytesInRange:` zero-fills if out of bounds.
+        // `replaceBytesInRange:` would zero-fill if out of bounds, but that
+        // is very unlikely to be what was intended.
+        let len = self.len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range {range:?} out of bounds for data of length {len}",
+        );
         let ptr = bytes.as_ptr() as *mut c_void;
         unsafe { self.replaceBytesInRange_withBytes_length(range.into(), ptr, bytes.len()) }
     }
@@ -198,6 +198,35 @@ extern_methods!(
     }
 );
 
+#[cfg(feature = "NSString")]
+impl<V: Message> NSDictionary<crate::Foundation::NSString, V> {
+    /// Returns a reference to the value corresponding to the given string
+    /// key.
+    ///
+    /// This is a shorthand for creating an `NSString` from the key and
+    /// passing it to [`get`][Self::get]. The temporary string is released
+    /// again once the lookup is done.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "NSObject", doc = "```")]
+    #[cfg_attr(not(feature = "NSObject"), doc = "```ignore")]
+    /// use objc2_foundation::{ns_string, NSMutableDictionary, NSObject};
+    ///
+    /// let mut dict = NSMutableDictionary::new();
+    /// dict.insert_id(ns_string!("one"), NSObject::new());
+    /// assert!(dict.get_str("one").is_some());
+    /// assert!(dict.get_str("two").is_none());
+    /// ```
+    #[doc(alias = "objectForKey:")]
+    pub fn get_str(&self, key: &str) -> Option<&V> {
+        let key = crate::Foundation::NSString::from_str(key);
+        // The dictionary does not keep a reference to the key that is used
+        // for the lookup, so the returned value is not tied to it.
+        self.get(&key)
+    }
+}
+
 impl<K: Message, V: Message> NSDictionary<K, V> {
     pub fn len(&self) -> usize {
         self.count()
@@ -306,6 +335,81 @@ impl<K: Message, V: Message> NSDictionary<K, V> {
         // another reference to them (via. `NSArray`) is sound.
         unsafe { self.allValues() }
     }
+
+    /// Call the given closure with each key-value pair in the dictionary.
+    ///
+    /// This is faster than iterating over the keys and looking up each
+    /// value, since the dictionary passes the key and the value to the
+    /// closure directly.
+    ///
+    /// See [`enumerate_while`][Self::enumerate_while] for a version that
+    /// can stop early.
+    ///
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "NSString", feature = "NSObject"), doc = "```")]
+    #[cfg_attr(
+        not(all(feature = "NSString", feature = "NSObject")),
+        doc = "```ignore"
+    )]
+    /// use objc2_foundation::{ns_string, NSMutableDictionary, NSObject};
+    ///
+    /// let mut dict = NSMutableDictionary::new();
+    /// dict.insert_id(ns_string!("one"), NSObject::new());
+    /// dict.insert_id(ns_string!("two"), NSObject::new());
+    ///
+    /// let mut count = 0;
+    /// dict.enumerate(|_key, _value| count += 1);
+    /// assert_eq!(count, 2);
+    /// ```
+    #[doc(alias = "enumerateKeysAndObjectsUsingBlock:")]
+    #[cfg(feature = "block2")]
+    pub fn enumerate<'a, F: FnMut(&'a K, &'a V)>(&'a self, mut f: F) {
+        self.enumerate_while(|key, value| {
+            f(key, value);
+            true
+        });
+    }
+
+    /// Call the given closure with each key-value pair in the dictionary,
+    /// until the closure returns `false`.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Unwinding out of a block into Objective-C is not allowed, so the
+    /// process is aborted if the closure panics.
+    #[doc(alias = "enumerateKeysAndObjectsUsingBlock:")]
+    #[cfg(feature = "block2")]
+    pub fn enumerate_while<'a, F: FnMut(&'a K, &'a V) -> bool>(&'a self, f: F) {
+        use core::cell::RefCell;
+
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        let f = RefCell::new(f);
+        let block = StackBlock::new(
+            |key: NonNull<K>, value: NonNull<V>, mut stop: NonNull<Bool>| {
+                let guard = util::AbortOnUnwind;
+                // SAFETY: The key and the value are stored in the
+                // dictionary, which is borrowed for the duration of the
+                // enumeration.
+                let (key, value) = unsafe { (key.as_ref(), value.as_ref()) };
+                if !(f.borrow_mut())(key, value) {
+                    // SAFETY: The stop pointer is valid for writes.
+                    unsafe { *stop.as_mut() = Bool::YES };
+                }
+                mem::forget(guard);
+            },
+        );
+        // SAFETY: The block has the correct signature, and is only used for
+        // the duration of the call.
+        //
+        // The dictionary cannot be mutated during the enumeration, since we
+        // hold a shared reference to it.
+        let _: () = unsafe { objc2::msg_send![self, enumerateKeysAndObjectsUsingBlock: &*block] };
+    }
 }
 
 impl<K: Message + Eq + Hash + HasStableHash, V: Message> NSMutableDictionary<K, V> {
@@ -373,6 +477,37 @@ impl<K: Message + Eq + Hash + HasStableHash, V: Message> NSMutableDictionary<K,
         old_obj
     }
 
+    /// Adds the entries from another dictionary to this one.
+    ///
+    /// If a key is present in both dictionaries, the value from `other`
+    /// replaces the existing value.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "NSString", doc = "```")]
+    #[cfg_attr(not(feature = "NSString"), doc = "```ignore")]
+    /// use objc2_foundation::{ns_string, NSDictionary, NSMutableDictionary};
+    ///
+    /// let mut dict = NSMutableDictionary::from_slice(
+    ///     &[ns_string!("a"), ns_string!("b")],
+    ///     &[ns_string!("1"), ns_string!("2")],
+    /// );
+    /// let other = NSDictionary::from_slice(&[ns_string!("b")], &[ns_string!("3")]);
+    /// dict.extend_from(&other);
+    /// assert_eq!(dict.get(ns_string!("b")), Some(ns_string!("3")));
+    /// ```
+    #[cfg(feature = "NSObject")]
+    #[doc(alias = "addEntriesFromDictionary:")]
+    pub fn extend_from(&mut self, other: &NSDictionary<K, V>)
+    where
+        K: NSCopying + CounterpartOrSelf<Immutable = K>,
+        V: IsRetainable,
+    {
+        // SAFETY: The values are `IsRetainable`, and hence safe for the
+        // collection to retain, and the keys are copied.
+        unsafe { self.addEntriesFromDictionary(other) };
+    }
+
     /// Removes a key from the dictionary, returning the value at the key
     /// if the key was previously in the dictionary.
     ///
@@ -589,11 +724,26 @@ impl<'a, K: Message + Eq + Hash, V: Message + IsMutable> IndexMut<&'a K>
 }
 
 impl<K: fmt::Debug + Message, V: fmt::Debug + Message> fmt::Debug for NSDictionary<K, V> {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (keys, values) = self.to_vecs();
         let iter = keys.into_iter().zip(values);
-        f.debug_map().entries(iter).finish()
+        // Limit the number of entries with the precision, see `NSArray`.
+        if let Some(limit) = f.precision() {
+            let len = self.len();
+            let mut map = f.debug_map();
+            map.entries(iter.take(limit).map(|(key, value)| {
+                (
+                    util::DebugWithoutPrecision(key),
+                    util::DebugWithoutPrecision(value),
+                )
+            }));
+            if len > limit {
+                map.entry(&format_args!("..."), &format_args!("..."));
+            }
+            map.finish()
+        } else {
+            f.debug_map().entries(iter).finish()
+        }
     }
 }
 
@@ -603,3 +753,17 @@ impl<K: fmt::Debug + Message, V: fmt::Debug + Message> fmt::Debug for NSMutableD
         fmt::Debug::fmt(&**self, f)
     }
 }
+
+/// Inserts the key-value pairs. If a key is already present, its value is
+/// replaced.
+#[cfg(feature = "NSObject")]
+impl<K, V: Message> Extend<(Id<K>, Id<V>)> for NSMutableDictionary<K, V>
+where
+    K: Message + Eq + Hash + HasStableHash + NSCopying + CounterpartOrSelf<Immutable = K>,
+{
+    fn extend<I: IntoIterator<Item = (Id<K>, Id<V>)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |(key, value)| {
+            let _ = self.insert_id(&key, value);
+        })
+    }
+}
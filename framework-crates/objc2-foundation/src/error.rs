@@ -27,6 +27,64 @@ impl NSError {
     pub fn NSLocalizedDescriptionKey() -> &'static crate::NSErrorUserInfoKey {
         unsafe { crate::NSLocalizedDescriptionKey }
     }
+
+    /// A description of the reason the error occurred, if any.
+    #[doc(alias = "localizedFailureReason")]
+    #[cfg(feature = "NSString")]
+    pub fn localized_failure_reason(&self) -> Option<objc2::rc::Id<crate::NSString>> {
+        self.localizedFailureReason()
+    }
+
+    /// A description of how the user may recover from the error, if any.
+    #[doc(alias = "localizedRecoverySuggestion")]
+    #[cfg(feature = "NSString")]
+    pub fn localized_recovery_suggestion(&self) -> Option<objc2::rc::Id<crate::NSString>> {
+        self.localizedRecoverySuggestion()
+    }
+
+    /// The error that caused this error, if any.
+    ///
+    /// This is the value for `NSUnderlyingErrorKey` in the user info
+    /// dictionary. Returns `None` if the key is absent, or if the value is
+    /// not an `NSError`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Walk the chain of underlying errors.
+    ///
+    /// ```
+    /// use objc2_foundation::NSError;
+    ///
+    /// # let error = NSError::new(42, objc2_foundation::ns_string!("MyDomain"));
+    /// let mut current = Some(error);
+    /// while let Some(error) = current {
+    ///     println!("{error}");
+    ///     current = error.underlying_error();
+    /// }
+    /// ```
+    #[doc(alias = "NSUnderlyingErrorKey")]
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSString")]
+    pub fn underlying_error(&self) -> Option<objc2::rc::Id<Self>> {
+        use objc2::rc::Id;
+        use objc2::runtime::{AnyObject, NSObject, NSObjectProtocol};
+        use objc2::sel;
+
+        // SAFETY: The key is a constant string
+        let key = unsafe { crate::NSUnderlyingErrorKey };
+        let obj = self.userInfo().get_retained(key)?;
+        if obj.class().responds_to(sel!(isKindOfClass:)) {
+            // SAFETY: We only use `isKindOfClass:` on NSObject
+            let ptr: *const AnyObject = &*obj;
+            let is_error = unsafe { &*ptr.cast::<NSObject>() }.is_kind_of::<Self>();
+            if is_error {
+                // SAFETY: Just checked that the object is an `NSError`
+                return Some(unsafe { Id::cast::<Self>(obj) });
+            }
+        }
+        None
+    }
 }
 
 #[cfg(feature = "NSString")]
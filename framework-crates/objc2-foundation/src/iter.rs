@@ -850,6 +850,23 @@ mod tests {
         assert!(enumerator.enumerate().all(|(i, obj)| obj.as_usize() == i));
     }
 
+    #[test]
+    fn test_size_hint() {
+        let vec = (0..4).map(NSNumber::new_usize).collect();
+        let array = NSArray::from_vec(vec);
+
+        let mut enumerator = array.iter();
+        assert_eq!(enumerator.size_hint(), (0, Some(4)));
+        enumerator.next().unwrap();
+        // The lower bound depends on how many items were fetched in the
+        // first batch, while the upper bound is the length of the array.
+        let (lower, upper) = enumerator.size_hint();
+        assert!(lower <= 3);
+        assert_eq!(upper, Some(4));
+        let rest: alloc::vec::Vec<_> = enumerator.collect();
+        assert_eq!(rest.len(), 3);
+    }
+
     #[test]
     fn test_into_enumerator() {
         let vec = (0..4).map(NSNumber::new_usize).collect();
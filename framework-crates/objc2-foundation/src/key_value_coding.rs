@@ -0,0 +1,82 @@
+use objc2::mutability::{IsMutable, IsRetainable};
+use objc2::rc::Id;
+use objc2::runtime::{AnyObject, NSObject};
+use objc2::{msg_send, msg_send_id, ClassType};
+
+use crate::Foundation::NSString;
+
+/// Helpers for accessing properties of objects by name using key-value
+/// coding.
+///
+/// This is implemented for all subclasses of `NSObject`, since that is
+/// where key-value coding is implemented (proxies are not supported).
+///
+/// Setting values mutates the object, so it is only possible through a
+/// shared reference for classes that are [`IsRetainable`], and through a
+/// mutable reference for classes that are [`IsMutable`]. This is the main
+/// difference from the generated `valueForKey` and `setValue_forKey`
+/// methods on `NSObject`, which take `&self` regardless of mutability.
+///
+/// See [Apple's documentation][apple-doc] for details.
+///
+/// [apple-doc]: https://developer.apple.com/documentation/objectivec/nsobject/nskeyvaluecoding
+pub trait NSKeyValueCodingExt: ClassType + AsRef<NSObject> {
+    /// Get the value of the property identified by the given key.
+    ///
+    /// Scalar values are returned wrapped in `NSNumber` or `NSValue`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must support key-value coding for the given key.
+    ///
+    /// If it does not, the default implementation throws an
+    /// `NSUndefinedKeyException`, which is undefined behaviour unless the
+    /// exception is caught (e.g. using `objc2::exception::catch`).
+    #[doc(alias = "valueForKey:")]
+    unsafe fn value_for_key(&self, key: &NSString) -> Option<Id<AnyObject>> {
+        unsafe { msg_send_id![self, valueForKey: key] }
+    }
+
+    /// Set the value of the property identified by the given key.
+    ///
+    /// Scalar properties must be given values wrapped in `NSNumber` or
+    /// `NSValue`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must support key-value coding for the given key, see
+    /// [`value_for_key`][Self::value_for_key] for details.
+    ///
+    /// Additionally, the value must be of the type that the property
+    /// expects, and `None` must only be given to properties that accept nil.
+    #[doc(alias = "setValue:forKey:")]
+    unsafe fn set_value_for_key(&self, value: Option<&AnyObject>, key: &NSString)
+    where
+        Self: IsRetainable,
+    {
+        unsafe { msg_send![self, setValue: value, forKey: key] }
+    }
+
+    /// Set the value of the property identified by the given key on a
+    /// mutable object.
+    ///
+    /// This is the same as [`set_value_for_key`][Self::set_value_for_key],
+    /// but for classes like `NSMutableDictionary` whose contents may only be
+    /// mutated through a mutable reference.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`set_value_for_key`][Self::set_value_for_key].
+    #[doc(alias = "setValue:forKey:")]
+    unsafe fn set_value_for_key_mut(&mut self, value: Option<&AnyObject>, key: &NSString)
+    where
+        Self: IsMutable,
+    {
+        unsafe { msg_send![self, setValue: value, forKey: key] }
+    }
+}
+
+impl<T: ClassType + AsRef<NSObject>> NSKeyValueCodingExt for T {}
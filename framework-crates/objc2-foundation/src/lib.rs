@@ -86,6 +86,10 @@ pub mod array;
 mod attributed_string;
 #[cfg(feature = "NSBundle")]
 mod bundle;
+mod callback_context;
+#[cfg(feature = "NSObjCRuntime")]
+#[cfg(feature = "block2")]
+mod comparator;
 #[cfg(feature = "NSObjCRuntime")]
 mod comparison_result;
 #[cfg(feature = "NSObject")]
@@ -108,14 +112,29 @@ mod generated;
 mod generics;
 #[cfg(feature = "NSGeometry")]
 mod geometry;
+#[cfg(feature = "NSKeyValueCoding")]
+#[cfg(feature = "NSString")]
+mod key_value_coding;
 mod macros;
+#[cfg(feature = "NSNotification")]
+#[cfg(feature = "NSOperation")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "block2")]
+mod notification;
 mod ns_consumed;
+#[cfg(feature = "NSNull")]
+mod null;
 #[cfg(feature = "NSValue")]
 mod number;
+mod objc_key;
 #[cfg(feature = "NSProcessInfo")]
 mod process_info;
 #[cfg(feature = "NSRange")]
 mod range;
+#[cfg(any(test, feature = "unstable-retain-count"))]
+mod retain_count;
+#[cfg(feature = "serde")]
+mod serde;
 #[cfg(feature = "NSSet")]
 pub mod set;
 #[cfg(feature = "NSString")]
@@ -131,6 +150,10 @@ mod uuid;
 #[cfg(feature = "NSValue")]
 mod value;
 
+pub use self::callback_context::CallbackContext;
+#[cfg(feature = "NSObjCRuntime")]
+#[cfg(feature = "block2")]
+pub use self::comparator::ComparatorBlock;
 #[cfg(feature = "NSObjCRuntime")]
 pub use self::comparison_result::NSComparisonResult;
 #[cfg(feature = "NSObject")]
@@ -145,16 +168,27 @@ pub use self::generated::*;
 pub use self::generics::*;
 #[cfg(feature = "NSGeometry")]
 pub use self::geometry::{CGFloat, CGPoint, CGRect, CGSize, NSPoint, NSRect, NSRectEdge, NSSize};
+#[cfg(feature = "NSKeyValueCoding")]
+#[cfg(feature = "NSString")]
+pub use self::key_value_coding::NSKeyValueCodingExt;
 #[cfg(feature = "NSMapTable")]
 pub use self::ns_consumed::NSFreeMapTable;
+pub use self::objc_key::ObjcKey;
 #[cfg(feature = "NSRange")]
 pub use self::range::NSRange;
+#[cfg(any(test, feature = "unstable-retain-count"))]
+pub use self::retain_count::retain_count;
+#[cfg(feature = "serde")]
+pub use self::serde::DeserializeId;
+#[cfg(feature = "NSRange")]
+#[cfg(feature = "NSString")]
+pub use self::string::FromNSStringError;
 pub use self::thread::MainThreadMarker;
 #[cfg(feature = "NSThread")]
 pub use self::thread::{is_main_thread, is_multi_threaded};
 #[cfg(feature = "NSThread")]
 #[cfg(feature = "dispatch")]
-pub use self::thread::{run_on_main, MainThreadBound};
+pub use self::thread::{dispatch_to_main, run_on_main, MainThreadBound};
 
 // Available under Foundation, so makes sense here as well:
 // https://developer.apple.com/documentation/foundation/numbers_data_and_basic_values?language=objc
@@ -1,2 +1,3 @@
 #[cfg(feature = "NSString")]
 mod ns_string;
+mod nsstring_format;
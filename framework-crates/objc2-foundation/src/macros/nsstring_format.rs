@@ -0,0 +1,44 @@
+/// Create a [`NSString`] using interpolation of runtime expressions.
+///
+/// This is the `NSString` equivalent of [`format!`], and accepts the same
+/// syntax. It is a safer alternative to `-[NSString stringWithFormat:]`,
+/// since the arguments are type-checked by the compiler, and since the
+/// format string must be a literal.
+///
+/// [`NSString`]: crate::Foundation::NSString
+/// [`format!`]: std::format
+///
+///
+/// # Objects
+///
+/// Objective-C's `%@` format specifier inserts the object's `description`.
+/// The equivalent here is `{:?}`, since the [`Debug`] implementation of
+/// objects uses `description`.
+///
+/// Note that [`NSString`]'s [`Debug`] implementation quotes the string, like
+/// [`str`]'s does; use `{}` to insert the string as-is.
+///
+/// [`Debug`]: core::fmt::Debug
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::{nsstring_format, ns_string, NSObject};
+///
+/// let name = ns_string!("world");
+/// let string = nsstring_format!("Hello, {name}! {}", 42);
+/// assert_eq!(string.to_string(), "Hello, world! 42");
+///
+/// let obj = NSObject::new();
+/// let string = nsstring_format!("{obj:?}");
+/// assert!(string.to_string().starts_with("<NSObject: "));
+/// ```
+#[cfg(feature = "NSString")]
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! nsstring_format {
+    ($($arg:tt)*) => {
+        $crate::NSString::from_str(&$crate::__macro_helpers::format!($($arg)*))
+    };
+}
@@ -0,0 +1,91 @@
+use core::mem;
+use core::ptr::NonNull;
+
+use block2::RcBlock;
+use objc2::rc::Id;
+use objc2::runtime::{AnyObject, NSObjectProtocol, ProtocolObject};
+use objc2::{msg_send, msg_send_id};
+
+use crate::util::AbortOnUnwind;
+use crate::Foundation::{
+    NSNotification, NSNotificationCenter, NSNotificationName, NSOperationQueue,
+};
+
+impl NSNotificationCenter {
+    /// Register a closure to be called when a matching notification is
+    /// posted.
+    ///
+    /// If `name` is given, only notifications with that name are delivered,
+    /// and if `object` is given, only notifications posted by that object
+    /// are delivered.
+    ///
+    /// If `queue` is `None`, the closure is called synchronously on the
+    /// thread that posted the notification, otherwise it is added to the
+    /// given queue. In both cases this may be a different thread than the
+    /// current one, which is why the closure must be [`Send`] and [`Sync`].
+    ///
+    /// The notification center keeps the closure alive until the returned
+    /// observer is passed to [`remove_observer`][Self::remove_observer].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Unwinding out of a block into Objective-C is not allowed, so the
+    /// process is aborted if the closure panics.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSNotificationCenter};
+    ///
+    /// let center = unsafe { NSNotificationCenter::defaultCenter() };
+    /// let name = ns_string!("MyNotification");
+    /// let observer = center.add_observer_for_name(Some(name), None, None, |notification| {
+    ///     println!("received {:?}", unsafe { notification.name() });
+    /// });
+    ///
+    /// unsafe { center.postNotificationName_object(name, None) };
+    ///
+    /// center.remove_observer(&observer);
+    /// ```
+    #[doc(alias = "addObserverForName:object:queue:usingBlock:")]
+    pub fn add_observer_for_name(
+        &self,
+        name: Option<&NSNotificationName>,
+        object: Option<&AnyObject>,
+        queue: Option<&NSOperationQueue>,
+        f: impl Fn(&NSNotification) + Send + Sync + 'static,
+    ) -> Id<ProtocolObject<dyn NSObjectProtocol>> {
+        let block = RcBlock::new(move |notification: NonNull<NSNotification>| {
+            let guard = AbortOnUnwind;
+            // SAFETY: The notification is valid for the duration of the
+            // call.
+            f(unsafe { notification.as_ref() });
+            mem::forget(guard);
+        });
+        // SAFETY: The block has the correct signature, and is copied by the
+        // notification center. The closure is `Send + Sync`, so it is safe
+        // to call from whichever thread the notification is delivered on.
+        unsafe {
+            msg_send_id![
+                self,
+                addObserverForName: name,
+                object: object,
+                queue: queue,
+                usingBlock: &*block,
+            ]
+        }
+    }
+
+    /// Unregister an observer that was previously registered with
+    /// [`add_observer_for_name`][Self::add_observer_for_name].
+    ///
+    /// This releases the closure that was registered along with it.
+    #[doc(alias = "removeObserver:")]
+    pub fn remove_observer(&self, observer: &ProtocolObject<dyn NSObjectProtocol>) {
+        // SAFETY: The observer is a valid object, and removing an observer
+        // that is not registered is a no-op.
+        unsafe { msg_send![self, removeObserver: observer] }
+    }
+}
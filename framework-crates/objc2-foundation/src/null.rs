@@ -0,0 +1,54 @@
+//! Utilities for the `NSNull` class.
+use core::ptr;
+
+use objc2::mutability::IsRetainable;
+use objc2::rc::Id;
+use objc2::runtime::AnyObject;
+use objc2::Message;
+
+use crate::Foundation::NSNull;
+
+impl NSNull {
+    /// Convert an optional object to an object that can be stored in a
+    /// collection, using the [`NSNull`] singleton in place of `None`.
+    ///
+    /// Collections like `NSArray` and `NSDictionary` cannot contain `nil`,
+    /// so `NSNull` is conventionally used to represent a missing value
+    /// instead. Use [`object_to_option`][Self::object_to_option] to convert
+    /// the object back when reading it from the collection.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSMutableArray, NSNull, NSObject};
+    ///
+    /// let obj = NSObject::new();
+    /// let mut array = NSMutableArray::new();
+    /// array.push(NSNull::option_to_object(Some(&*obj)));
+    /// array.push(NSNull::option_to_object(None::<&NSObject>));
+    ///
+    /// assert!(NSNull::object_to_option(&array[0]).is_some());
+    /// assert!(NSNull::object_to_option(&array[1]).is_none());
+    /// ```
+    pub fn option_to_object<T: Message + IsRetainable>(obj: Option<&T>) -> Id<AnyObject> {
+        match obj {
+            // SAFETY: Any object can be converted to `AnyObject`.
+            Some(obj) => unsafe { Id::cast(obj.retain()) },
+            None => Id::into_super(Id::into_super(Self::null())),
+        }
+    }
+
+    /// Convert an object read from a collection to an optional object,
+    /// returning `None` if the object is the [`NSNull`] singleton.
+    ///
+    /// This is the reverse of [`option_to_object`][Self::option_to_object].
+    pub fn object_to_option(obj: &AnyObject) -> Option<&AnyObject> {
+        let null = Self::null();
+        if ptr::eq(obj, Id::as_ptr(&null).cast()) {
+            None
+        } else {
+            Some(obj)
+        }
+    }
+}
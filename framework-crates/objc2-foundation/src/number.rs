@@ -15,6 +15,10 @@
 //! <https://github.com/nvzqz/fruity/blob/811d7787495eaaee6bc39d372004e5d96ef9f49b/src/foundation/ns_number.rs#L328-L401>
 //!
 //! (Same goes for `NSNull`).
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
 #[cfg(feature = "NSObjCRuntime")]
 use core::cmp::Ordering;
 use core::fmt;
@@ -41,6 +45,61 @@ macro_rules! def_new_fn {
     )*}
 }
 
+/// The number of small integers cached by [`cached`], for values in
+/// `-1..=255`.
+#[cfg(feature = "std")]
+const CACHE_LEN: usize = 257;
+
+/// Look up a small integer in a thread-local cache, or create and cache it.
+///
+/// Foundation already avoids allocating for most small integers (using
+/// tagged pointers), but creating them still has to go through the runtime;
+/// the cache makes it cheap to box e.g. loop counters.
+///
+/// Each constructor has its own cache, such that the objects are
+/// indistinguishable from freshly created ones, even when looking at their
+/// encoding. Since `NSNumber` is immutable, sharing them is fine.
+#[cfg(feature = "std")]
+fn cached(
+    cache: &'static std::thread::LocalKey<RefCell<Vec<Option<Id<NSNumber>>>>>,
+    val: Option<i64>,
+    create: impl Fn() -> Id<NSNumber>,
+) -> Id<NSNumber> {
+    let index = match val.and_then(|val| val.checked_add(1)).map(usize::try_from) {
+        Some(Ok(index)) if index < CACHE_LEN => index,
+        _ => return create(),
+    };
+    cache
+        .try_with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if cache.is_empty() {
+                cache.resize_with(CACHE_LEN, || None);
+            }
+            cache[index].get_or_insert_with(&create).clone()
+        })
+        // The cache may be inaccessible while the thread is exiting.
+        .unwrap_or_else(|_| create())
+}
+
+macro_rules! def_new_cached_fn {
+    {$(
+        ($fn_name:ident($fn_inp:ty); $new_fn_name:ident),
+    )*} => {$(
+        #[doc = concat!("Same as [`", stringify!($new_fn_name), "`][Self::", stringify!($new_fn_name), "], but small integers are cached per thread.")]
+        ///
+        /// The cache covers values in `-1..=255`, and makes it cheap to box
+        /// e.g. loop counters. The returned objects may be the same instance
+        /// for the same value.
+        #[cfg(feature = "std")]
+        pub fn $fn_name(val: $fn_inp) -> Id<Self> {
+            std::thread_local! {
+                static CACHE: RefCell<Vec<Option<Id<NSNumber>>>> = RefCell::new(Vec::new());
+            }
+            cached(&CACHE, i64::try_from(val).ok(), || Self::$new_fn_name(val))
+        }
+    )*}
+}
+
 /// Creation methods.
 impl NSNumber {
     def_new_fn! {
@@ -59,6 +118,19 @@ impl NSNumber {
         (new_f64(f64); numberWithDouble),
     }
 
+    def_new_cached_fn! {
+        (new_i8_cached(i8); new_i8),
+        (new_u8_cached(u8); new_u8),
+        (new_i16_cached(i16); new_i16),
+        (new_u16_cached(u16); new_u16),
+        (new_i32_cached(i32); new_i32),
+        (new_u32_cached(u32); new_u32),
+        (new_i64_cached(i64); new_i64),
+        (new_u64_cached(u64); new_u64),
+        (new_isize_cached(isize); new_isize),
+        (new_usize_cached(usize); new_usize),
+    }
+
     #[inline]
     #[cfg(feature = "NSGeometry")]
     pub fn new_cgfloat(val: crate::Foundation::CGFloat) -> Id<Self> {
@@ -0,0 +1,102 @@
+//! A wrapper for using Objective-C objects as keys in Rust collections.
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use objc2::mutability::{HasStableHash, IsIdCloneable};
+use objc2::rc::Id;
+use objc2::runtime::NSObjectProtocol;
+use objc2::{msg_send, Message};
+
+/// An object that is hashed and compared using Objective-C's `-hash` and
+/// `isEqual:`.
+///
+/// This is useful for using objects as keys in Rust collections like
+/// `HashMap` and `HashSet`, regardless of whether the object's type
+/// implements [`Hash`] and [`Eq`] itself. Two keys are equal if
+/// `isEqual:` returns `YES` for them, even if they are different instances.
+///
+/// The object's hash must not change while it is in a collection, which is
+/// why this requires [`HasStableHash`].
+///
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use objc2_foundation::{NSString, ObjcKey};
+///
+/// let mut map = HashMap::new();
+/// map.insert(ObjcKey::new(NSString::from_str("key")), 1);
+/// assert_eq!(map.get(&ObjcKey::new(NSString::from_str("key"))), Some(&1));
+/// ```
+pub struct ObjcKey<T: ?Sized> {
+    obj: Id<T>,
+}
+
+impl<T: ?Sized> ObjcKey<T> {
+    /// Wrap the given object.
+    #[inline]
+    pub fn new(obj: Id<T>) -> Self {
+        Self { obj }
+    }
+
+    /// Unwrap the object.
+    #[inline]
+    pub fn into_inner(this: Self) -> Id<T> {
+        this.obj
+    }
+}
+
+impl<T: ?Sized> Deref for ObjcKey<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.obj
+    }
+}
+
+impl<T: ?Sized> From<Id<T>> for ObjcKey<T> {
+    #[inline]
+    fn from(obj: Id<T>) -> Self {
+        Self::new(obj)
+    }
+}
+
+impl<T: Message + NSObjectProtocol + HasStableHash> PartialEq for ObjcKey<T> {
+    #[inline]
+    #[doc(alias = "isEqual:")]
+    fn eq(&self, other: &Self) -> bool {
+        // SAFETY: The object implements `NSObjectProtocol`, and hence
+        // responds to `isEqual:`.
+        unsafe { msg_send![&*self.obj, isEqual: &*other.obj] }
+    }
+}
+
+/// `isEqual:` is assumed to be reflexive, as it is for most types.
+impl<T: Message + NSObjectProtocol + HasStableHash> Eq for ObjcKey<T> {}
+
+impl<T: Message + NSObjectProtocol + HasStableHash> Hash for ObjcKey<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // SAFETY: The object implements `NSObjectProtocol`, and hence
+        // responds to `hash`.
+        let hash: usize = unsafe { msg_send![&*self.obj, hash] };
+        hash.hash(state);
+    }
+}
+
+impl<T: Message + IsIdCloneable> Clone for ObjcKey<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.obj.clone())
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for ObjcKey<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ObjcKey").field(&self.obj).finish()
+    }
+}
@@ -0,0 +1,36 @@
+//! Diagnostic helpers for inspecting reference counts in tests.
+use objc2::runtime::NSObjectProtocol;
+
+/// The current reference count of the object.
+///
+/// This calls `-retainCount`, and is intended for debugging and for
+/// writing tests that check that objects are not leaked.
+///
+/// The returned value is inherently unreliable, and should never be relied
+/// upon outside of tests: objects may be retained and autoreleased
+/// internally by the frameworks, constant objects (like string literals and
+/// small numbers) can report arbitrary values, and the optimizations that
+/// Foundation performs when copying objects are undocumented and differ
+/// between runtimes.
+///
+/// Only available with the `"unstable-retain-count"` feature.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::{retain_count, NSObject};
+///
+/// let obj = NSObject::new();
+/// assert_eq!(retain_count(&*obj), 1);
+/// let obj2 = obj.clone();
+/// assert_eq!(retain_count(&*obj), 2);
+/// drop(obj2);
+/// assert_eq!(retain_count(&*obj), 1);
+/// ```
+#[doc(alias = "retainCount")]
+#[doc(alias = "CFGetRetainCount")]
+#[inline]
+pub fn retain_count<T: ?Sized + NSObjectProtocol>(obj: &T) -> usize {
+    obj.retainCount()
+}
@@ -0,0 +1,332 @@
+//! Support for serializing and deserializing Foundation objects with
+//! [`serde`].
+//!
+//! `NSString` is serialized as a string, `NSNumber` as a boolean or a
+//! number, `NSNull` as unit, `NSArray` as a sequence and `NSDictionary` as a
+//! map.
+use core::fmt;
+use core::marker::PhantomData;
+
+use objc2::rc::Id;
+use objc2::Message;
+use serde::de::{DeserializeSeed, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Objective-C objects that can be deserialized with [`serde`].
+///
+/// Since [`Id`] is defined in `objc2`, it is not possible for this crate to
+/// implement [`serde::Deserialize`] for e.g. `Id<NSString>`. Instead, this
+/// trait provides [`deserialize_id`][Self::deserialize_id], which can be
+/// used directly, or with `#[serde(deserialize_with = "...")]` (e.g.
+/// `#[serde(deserialize_with = "NSString::deserialize_id")]` on a field of
+/// type `Id<NSString>`).
+///
+///
+/// # Examples
+///
+/// Deserialize an `NSString` from JSON.
+///
+#[cfg_attr(feature = "NSString", doc = "```")]
+#[cfg_attr(not(feature = "NSString"), doc = "```ignore")]
+/// use objc2_foundation::{DeserializeId, NSString};
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""hello""#);
+/// let string = NSString::deserialize_id(&mut deserializer).unwrap();
+/// assert_eq!(string.to_string(), "hello");
+/// ```
+pub trait DeserializeId: Message {
+    /// Deserialize a new object.
+    fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Id<Self>, D::Error>;
+}
+
+/// Helper for deserializing the elements of collections.
+#[allow(dead_code)] // Unused if none of the collection features are enabled
+struct IdSeed<T: ?Sized>(PhantomData<T>);
+
+#[allow(dead_code)]
+impl<T: ?Sized> IdSeed<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, T: DeserializeId> DeserializeSeed<'de> for IdSeed<T> {
+    type Value = Id<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Id<T>, D::Error> {
+        T::deserialize_id(deserializer)
+    }
+}
+
+#[cfg(feature = "NSString")]
+mod string {
+    use objc2::rc::autoreleasepool_leaking;
+    use serde::de::Error;
+
+    use super::*;
+    use crate::Foundation::{NSMutableString, NSString};
+
+    impl Serialize for NSString {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            autoreleasepool_leaking(|pool| serializer.serialize_str(self.as_str(pool)))
+        }
+    }
+
+    impl Serialize for NSMutableString {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (**self).serialize(serializer)
+        }
+    }
+
+    struct StringVisitor;
+
+    impl<'de> Visitor<'de> for StringVisitor {
+        type Value = Id<NSString>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(NSString::from_str(v))
+        }
+    }
+
+    impl DeserializeId for NSString {
+        fn deserialize_id<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Id<Self>, D::Error> {
+            deserializer.deserialize_str(StringVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "NSValue")]
+mod number {
+    use objc2::encode::Encoding;
+    use serde::de::Error;
+
+    use super::*;
+    use crate::Foundation::NSNumber;
+
+    impl Serialize for NSNumber {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.encoding() {
+                // `BOOL` numbers have the same encoding as `char`, so (like
+                // `NSJSONSerialization`) we detect them by comparing with
+                // the shared instances returned by `numberWithBool:`.
+                Encoding::Char if core::ptr::eq(self, &*NSNumber::new_bool(self.as_bool())) => {
+                    serializer.serialize_bool(self.as_bool())
+                }
+                Encoding::Char
+                | Encoding::Short
+                | Encoding::Int
+                | Encoding::Long
+                | Encoding::LongLong => serializer.serialize_i64(self.as_i64()),
+                Encoding::UChar
+                | Encoding::UShort
+                | Encoding::UInt
+                | Encoding::ULong
+                | Encoding::ULongLong => serializer.serialize_u64(self.as_u64()),
+                Encoding::Float | Encoding::Double => serializer.serialize_f64(self.as_f64()),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    struct NumberVisitor;
+
+    impl<'de> Visitor<'de> for NumberVisitor {
+        type Value = Id<NSNumber>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a boolean or a number")
+        }
+
+        fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+            Ok(NSNumber::new_bool(v))
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(NSNumber::new_i64(v))
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(NSNumber::new_u64(v))
+        }
+
+        fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+            Ok(NSNumber::new_f64(v))
+        }
+    }
+
+    impl DeserializeId for NSNumber {
+        fn deserialize_id<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Id<Self>, D::Error> {
+            deserializer.deserialize_any(NumberVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "NSNull")]
+mod null {
+    use serde::de::Error;
+
+    use super::*;
+    use crate::Foundation::NSNull;
+
+    impl Serialize for NSNull {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_unit()
+        }
+    }
+
+    struct NullVisitor;
+
+    impl<'de> Visitor<'de> for NullVisitor {
+        type Value = Id<NSNull>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("null")
+        }
+
+        fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(NSNull::null())
+        }
+
+        fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(NSNull::null())
+        }
+    }
+
+    impl DeserializeId for NSNull {
+        fn deserialize_id<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Id<Self>, D::Error> {
+            deserializer.deserialize_unit(NullVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSEnumerator")]
+mod array {
+    use alloc::vec::Vec;
+
+    use serde::de::SeqAccess;
+    use serde::ser::SerializeSeq;
+
+    use super::*;
+    use crate::Foundation::{NSArray, NSMutableArray};
+
+    impl<T: Message + Serialize> Serialize for NSArray<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for obj in self.iter() {
+                seq.serialize_element(obj)?;
+            }
+            seq.end()
+        }
+    }
+
+    impl<T: Message + Serialize> Serialize for NSMutableArray<T> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (**self).serialize(serializer)
+        }
+    }
+
+    struct ArrayVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: DeserializeId> Visitor<'de> for ArrayVisitor<T> {
+        type Value = Id<NSArray<T>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut vec = Vec::new();
+            while let Some(obj) = seq.next_element_seed(IdSeed::<T>::new())? {
+                vec.push(obj);
+            }
+            Ok(NSArray::from_vec(vec))
+        }
+    }
+
+    impl<T: DeserializeId> DeserializeId for NSArray<T> {
+        fn deserialize_id<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Id<Self>, D::Error> {
+            deserializer.deserialize_seq(ArrayVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(feature = "NSDictionary")]
+mod dictionary {
+    use serde::ser::SerializeMap;
+
+    use super::*;
+    use crate::Foundation::{NSDictionary, NSMutableDictionary};
+
+    impl<K: Message + Serialize, V: Message + Serialize> Serialize for NSDictionary<K, V> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let (keys, objects) = self.to_vecs();
+            let mut map = serializer.serialize_map(Some(keys.len()))?;
+            for (key, obj) in keys.into_iter().zip(objects) {
+                map.serialize_entry(key, obj)?;
+            }
+            map.end()
+        }
+    }
+
+    impl<K: Message + Serialize, V: Message + Serialize> Serialize for NSMutableDictionary<K, V> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (**self).serialize(serializer)
+        }
+    }
+
+    #[cfg(feature = "NSObject")]
+    #[cfg(feature = "NSString")]
+    mod deserialize {
+        use alloc::vec::Vec;
+
+        use serde::de::MapAccess;
+
+        use super::*;
+        use crate::Foundation::NSString;
+
+        struct DictionaryVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: DeserializeId> Visitor<'de> for DictionaryVisitor<V> {
+            type Value = Id<NSDictionary<NSString, V>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map with string keys")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut keys = Vec::new();
+                let mut objects = Vec::new();
+                while let Some(key) = map.next_key_seed(IdSeed::<NSString>::new())? {
+                    objects.push(map.next_value_seed(IdSeed::<V>::new())?);
+                    keys.push(key);
+                }
+                let keys: Vec<&NSString> = keys.iter().map(|key| &**key).collect();
+                Ok(NSDictionary::from_vec(&keys, objects))
+            }
+        }
+
+        impl<V: DeserializeId> DeserializeId for NSDictionary<NSString, V> {
+            fn deserialize_id<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Id<Self>, D::Error> {
+                deserializer.deserialize_map(DictionaryVisitor(PhantomData))
+            }
+        }
+    }
+}
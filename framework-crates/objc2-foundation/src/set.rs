@@ -541,9 +541,18 @@ __impl_into_iter! {
 
 #[cfg(feature = "NSEnumerator")]
 impl<T: fmt::Debug + Message> fmt::Debug for NSSet<T> {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_set().entries(self).finish()
+        // Limit the number of elements with the precision, see `NSArray`.
+        if let Some(limit) = f.precision() {
+            let mut set = f.debug_set();
+            set.entries(self.iter().take(limit).map(util::DebugWithoutPrecision));
+            if self.len() > limit {
+                set.entry(&format_args!("..."));
+            }
+            set.finish()
+        } else {
+            f.debug_set().entries(self).finish()
+        }
     }
 }
 
@@ -1,9 +1,15 @@
+#[cfg(feature = "NSRange")]
+use alloc::string::String;
+#[cfg(feature = "NSRange")]
+use alloc::vec::Vec;
 #[cfg(feature = "NSObjCRuntime")]
 use core::cmp;
 use core::fmt;
 use core::ops::AddAssign;
 use core::panic::RefUnwindSafe;
 use core::panic::UnwindSafe;
+#[cfg(feature = "NSRange")]
+use core::ptr::NonNull;
 #[cfg(feature = "apple")]
 use core::slice;
 use core::str;
@@ -14,6 +20,10 @@ use objc2::rc::{autoreleasepool_leaking, Allocated, AutoreleasePool, Id};
 use objc2::runtime::__nsstring::{nsstring_len, nsstring_to_str, UTF8_ENCODING};
 use objc2::{ClassType, Message};
 
+#[cfg(feature = "NSArray")]
+use crate::Foundation::NSArray;
+#[cfg(feature = "NSRange")]
+use crate::Foundation::NSRange;
 use crate::{NSMutableString, NSString};
 
 // SAFETY: `NSString` is immutable and `NSMutableString` can only be mutated
@@ -125,6 +135,46 @@ impl NSString {
         unsafe { init_with_str(Self::alloc(), string) }
     }
 
+    /// Creates an autoreleased `NSString` by copying the given string slice.
+    ///
+    /// The string is released when the given autorelease pool is drained,
+    /// which is useful for temporary strings that are only needed as the
+    /// argument to a method call, and saves the bookkeeping of an [`Id`].
+    ///
+    /// Prefer using the [`ns_string!`] macro when possible.
+    ///
+    /// [`ns_string!`]: crate::Foundation::ns_string
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2::rc::autoreleasepool;
+    /// use objc2_foundation::NSString;
+    ///
+    /// autoreleasepool(|pool| {
+    ///     for i in 0..10 {
+    ///         let string = NSString::from_str_autoreleased(&i.to_string(), pool);
+    ///         assert_eq!(string.as_str(pool), i.to_string());
+    ///     }
+    /// });
+    /// ```
+    #[doc(alias = "initWithBytes:length:encoding:")]
+    pub fn from_str_autoreleased<'p>(string: &str, pool: AutoreleasePool<'p>) -> &'p Self {
+        Id::autorelease(Self::from_str(string), pool)
+    }
+
+    /// Creates an immutable `NSString` from the given file system path.
+    ///
+    /// Returns [`None`] if the path is not valid UTF-8.
+    ///
+    /// This cannot be a `From` implementation, since `Id` is defined in
+    /// `objc2`.
+    #[cfg(feature = "std")]
+    pub fn from_path(path: &std::path::Path) -> Option<Id<Self>> {
+        path.to_str().map(Self::from_str)
+    }
+
     // TODO: initWithBytesNoCopy:, maybe add lifetime parameter to NSString?
     // See https://github.com/nvzqz/fruity/blob/320efcf715c2c5fbd2f3084f671f2be2e03a6f2b/src/foundation/ns_string/mod.rs#L350-L381
     // Might be quite difficult, as Objective-C code might assume the NSString
@@ -132,6 +182,385 @@ impl NSString {
     // See https://github.com/drewcrawford/foundationr/blob/b27683417a35510e8e5d78a821f081905b803de6/src/nsstring.rs
 }
 
+impl NSString {
+    /// Split the string into the substrings that are separated by the given
+    /// separator.
+    ///
+    /// Like in Cocoa, adjacent separators, as well as separators at the
+    /// start or end of the string, result in empty substrings.
+    #[doc(alias = "componentsSeparatedByString:")]
+    #[cfg(feature = "NSArray")]
+    pub fn components_separated_by(&self, separator: &NSString) -> Id<NSArray<NSString>> {
+        self.componentsSeparatedByString(separator)
+    }
+
+    /// A new path made by appending the given component to this path,
+    /// separated by `/` if necessary.
+    ///
+    /// This follows Cocoa's path rules, so e.g. redundant slashes are
+    /// removed, which differs from `std::path::Path::join`.
+    #[doc(alias = "stringByAppendingPathComponent:")]
+    #[cfg(feature = "NSPathUtilities")]
+    pub fn appending_path_component(&self, component: &NSString) -> Id<NSString> {
+        self.stringByAppendingPathComponent(component)
+    }
+
+    /// The last component of the path.
+    ///
+    /// Trailing slashes are ignored, so the last component of `"/tmp/"` is
+    /// `"tmp"`, and the last component of `"/"` is `"/"`.
+    #[doc(alias = "lastPathComponent")]
+    #[cfg(feature = "NSPathUtilities")]
+    pub fn last_path_component(&self) -> Id<NSString> {
+        self.lastPathComponent()
+    }
+
+    /// The extension of the last path component, without the leading `.`.
+    ///
+    /// Returns an empty string if there is no extension.
+    #[doc(alias = "pathExtension")]
+    #[cfg(feature = "NSPathUtilities")]
+    pub fn path_extension(&self) -> Id<NSString> {
+        self.pathExtension()
+    }
+
+    /// A new path made by removing the last component from this path.
+    ///
+    /// Removing the last component of `"/tmp"` results in `"/"`, and of
+    /// `"tmp"` results in the empty string.
+    #[doc(alias = "stringByDeletingLastPathComponent")]
+    #[cfg(feature = "NSPathUtilities")]
+    pub fn deleting_last_path_component(&self) -> Id<NSString> {
+        self.stringByDeletingLastPathComponent()
+    }
+
+    /// Whether the string starts with the given prefix.
+    #[doc(alias = "hasPrefix:")]
+    pub fn has_prefix(&self, prefix: &NSString) -> bool {
+        self.hasPrefix(prefix)
+    }
+
+    /// Whether the string ends with the given suffix.
+    #[doc(alias = "hasSuffix:")]
+    pub fn has_suffix(&self, suffix: &NSString) -> bool {
+        self.hasSuffix(suffix)
+    }
+
+    /// Create a new string from the characters in the given range.
+    ///
+    /// Note that the range is in UTF-16 code units, not in bytes or
+    /// [`char`]s, see [`len_utf16`][Self::len_utf16].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[doc(alias = "substringWithRange:")]
+    #[cfg(feature = "NSRange")]
+    pub fn substring(&self, range: NSRange) -> Id<NSString> {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        assert!(
+            range.end() <= self.len_utf16(),
+            "range {range:?} out of bounds of string with UTF-16 length {}",
+            self.len_utf16(),
+        );
+        // SAFETY: The range is checked to be in bounds.
+        unsafe { self.substringWithRange(range) }
+    }
+
+    /// Find the range of the first occurrence of the given string.
+    ///
+    /// Returns [`None`] if the string is not found, or if `needle` is empty.
+    ///
+    /// Note that the range is in UTF-16 code units, not in bytes or
+    /// [`char`]s, see [`len_utf16`][Self::len_utf16].
+    #[doc(alias = "rangeOfString:")]
+    #[cfg(feature = "NSRange")]
+    pub fn range_of(&self, needle: &NSString) -> Option<NSRange> {
+        let range = self.rangeOfString(needle);
+        crate::util::not_found_to_none(range.location).map(|_| range)
+    }
+
+    /// The index of the first occurrence of the given string.
+    ///
+    /// This is similar to [`str::find`], except that the index is in UTF-16
+    /// code units, not in bytes, see [`len_utf16`][Self::len_utf16].
+    ///
+    /// Returns [`None`] if the string is not found, or if `needle` is empty.
+    #[doc(alias = "rangeOfString:")]
+    #[cfg(feature = "NSRange")]
+    pub fn find(&self, needle: &NSString) -> Option<usize> {
+        self.range_of(needle).map(|range| range.location)
+    }
+
+    /// The index of the last occurrence of the given string.
+    ///
+    /// This is similar to [`str::rfind`], except that the index is in UTF-16
+    /// code units, not in bytes, see [`len_utf16`][Self::len_utf16].
+    ///
+    /// Returns [`None`] if the string is not found, or if `needle` is empty.
+    #[doc(alias = "rangeOfString:options:")]
+    #[doc(alias = "NSBackwardsSearch")]
+    #[cfg(feature = "NSRange")]
+    pub fn rfind(&self, needle: &NSString) -> Option<usize> {
+        let options = crate::Foundation::NSStringCompareOptions::NSBackwardsSearch;
+        // SAFETY: The options are valid for `rangeOfString:options:`.
+        let range: NSRange =
+            unsafe { objc2::msg_send![self, rangeOfString: needle, options: options] };
+        crate::util::not_found_to_none(range.location)
+    }
+
+    /// Compare the string with another string, using the rules of the given
+    /// locale.
+    ///
+    /// Locale-aware comparison is what you want when sorting strings that
+    /// are displayed to the user, since e.g. the order of accented
+    /// characters differs between languages. If `locale` is `None`, the
+    /// comparison is not localized.
+    ///
+    /// The `options` can be used to do case-insensitive or numeric
+    /// comparisons, see [`NSStringCompareOptions`].
+    ///
+    /// [`NSStringCompareOptions`]: crate::Foundation::NSStringCompareOptions
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSComparisonResult, NSLocale, NSStringCompareOptions};
+    ///
+    /// let swedish = NSLocale::localeWithLocaleIdentifier(ns_string!("sv_SE"));
+    /// let german = NSLocale::localeWithLocaleIdentifier(ns_string!("de_DE"));
+    /// let options = NSStringCompareOptions::empty();
+    ///
+    /// // In Swedish, "ä" sorts after "z", while in German it sorts with "a".
+    /// let a = ns_string!("ä");
+    /// let z = ns_string!("z");
+    /// assert_eq!(a.compare_with_locale(z, options, Some(&swedish)), NSComparisonResult::Descending);
+    /// assert_eq!(a.compare_with_locale(z, options, Some(&german)), NSComparisonResult::Ascending);
+    /// ```
+    #[doc(alias = "compare:options:range:locale:")]
+    #[doc(alias = "localizedCompare:")]
+    #[cfg(feature = "NSLocale")]
+    #[cfg(feature = "NSObjCRuntime")]
+    #[cfg(feature = "NSRange")]
+    pub fn compare_with_locale(
+        &self,
+        other: &NSString,
+        options: crate::Foundation::NSStringCompareOptions,
+        locale: Option<&crate::Foundation::NSLocale>,
+    ) -> crate::Foundation::NSComparisonResult {
+        let range = NSRange::new(0, self.len_utf16());
+        // SAFETY: The range covers the entire string, and the locale is
+        // either `nil` or an instance of `NSLocale`, as the method requires.
+        unsafe {
+            objc2::msg_send![
+                self,
+                compare: other,
+                options: options,
+                range: range,
+                locale: locale,
+            ]
+        }
+    }
+
+    /// A copy of the string with all characters converted to uppercase.
+    ///
+    /// This performs Unicode-correct case mapping, and may change the
+    /// length of the string (e.g. `"ß"` becomes `"SS"`). It uses the
+    /// non-localized rules, so the result does not depend on the user's
+    /// locale (e.g. Turkish dotless `ı` is not taken into account).
+    #[doc(alias = "uppercaseString")]
+    pub fn to_uppercase(&self) -> Id<NSString> {
+        self.uppercaseString()
+    }
+
+    /// A copy of the string with all characters converted to lowercase.
+    ///
+    /// See [`to_uppercase`][Self::to_uppercase] for details.
+    #[doc(alias = "lowercaseString")]
+    pub fn to_lowercase(&self) -> Id<NSString> {
+        self.lowercaseString()
+    }
+
+    /// A copy of the string with the first character of each word converted
+    /// to uppercase, and all other characters to lowercase.
+    ///
+    /// See [`to_uppercase`][Self::to_uppercase] for details.
+    #[doc(alias = "capitalizedString")]
+    pub fn to_capitalized(&self) -> Id<NSString> {
+        self.capitalizedString()
+    }
+
+    /// A copy of the string with whitespace and newlines removed from both
+    /// ends.
+    #[doc(alias = "stringByTrimmingCharactersInSet:")]
+    #[doc(alias = "whitespaceAndNewlineCharacterSet")]
+    #[cfg(feature = "NSCharacterSet")]
+    pub fn trimmed_whitespace(&self) -> Id<NSString> {
+        let set = crate::Foundation::NSCharacterSet::whitespaceAndNewlineCharacterSet();
+        self.stringByTrimmingCharactersInSet(&set)
+    }
+
+    /// A copy of the string with the characters in the given set removed
+    /// from both ends.
+    ///
+    /// The predefined character sets like
+    /// [`NSCharacterSet::punctuationCharacterSet`] are shared instances, so
+    /// these are cheap to retrieve.
+    ///
+    /// [`NSCharacterSet::punctuationCharacterSet`]: crate::Foundation::NSCharacterSet::punctuationCharacterSet
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSCharacterSet};
+    ///
+    /// let set = NSCharacterSet::characterSetWithCharactersInString(ns_string!("*!"));
+    /// let string = ns_string!("**hello, world!*");
+    /// assert_eq!(&*string.trimming(&set), ns_string!("hello, world"));
+    /// ```
+    #[doc(alias = "stringByTrimmingCharactersInSet:")]
+    #[cfg(feature = "NSCharacterSet")]
+    pub fn trimming(&self, set: &crate::Foundation::NSCharacterSet) -> Id<NSString> {
+        self.stringByTrimmingCharactersInSet(set)
+    }
+
+    /// Split the string into the substrings that are separated by any of the
+    /// characters in the given set.
+    ///
+    /// See [`components_separated_by`][Self::components_separated_by] for
+    /// details.
+    #[doc(alias = "componentsSeparatedByCharactersInSet:")]
+    #[cfg(feature = "NSArray")]
+    #[cfg(feature = "NSCharacterSet")]
+    pub fn components_separated_by_charset(
+        &self,
+        set: &crate::Foundation::NSCharacterSet,
+    ) -> Id<NSArray<NSString>> {
+        self.componentsSeparatedByCharactersInSet(set)
+    }
+
+    /// An iterator over the lines of the string.
+    ///
+    /// Lines are split at any of the Unicode line terminators (including
+    /// `\r\n`), and the line terminators are not included in the lines.
+    #[doc(alias = "enumerateLinesUsingBlock:")]
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSObject")]
+    pub fn lines(&self) -> impl Iterator<Item = Id<NSString>> {
+        use alloc::vec::Vec;
+        use core::cell::RefCell;
+        use core::ptr::NonNull;
+
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        use crate::Foundation::NSCopying;
+
+        let lines = RefCell::new(Vec::new());
+        let block = StackBlock::new(|line: NonNull<NSString>, _stop: NonNull<Bool>| {
+            // SAFETY: The line is a valid string for the duration of the
+            // block. We copy it to make sure it stays valid afterwards.
+            let line = unsafe { line.as_ref() };
+            lines.borrow_mut().push(line.copy());
+        });
+        // SAFETY: The block has the correct signature, and is only used for
+        // the duration of the call.
+        let _: () = unsafe { objc2::msg_send![self, enumerateLinesUsingBlock: &*block] };
+        drop(block);
+        lines.into_inner().into_iter()
+    }
+
+    /// Call the given closure with each of the substrings in the given range,
+    /// split according to `options`.
+    ///
+    /// The options determine what kind of substrings to enumerate, e.g.
+    /// words, sentences, lines or composed character sequences (roughly
+    /// grapheme clusters), using Cocoa's Unicode text segmentation rules.
+    ///
+    /// The closure is called with the substring and the range of it in
+    /// `self`. The substring is `None` if the
+    /// `NSStringEnumerationSubstringNotRequired` option is given.
+    ///
+    /// Note that the ranges are in UTF-16 code units, see
+    /// [`len_utf16`][Self::len_utf16].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// Unwinding out of a block into Objective-C is not allowed, so the
+    /// process is aborted if the closure panics.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Count the words in a string.
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSRange, NSStringEnumerationOptions};
+    ///
+    /// let s = ns_string!("Hello, world! How are you?");
+    /// let mut words = 0;
+    /// s.enumerate_substrings_in_range(
+    ///     NSRange::new(0, s.len_utf16()),
+    ///     NSStringEnumerationOptions::NSStringEnumerationByWords,
+    ///     |_word, _range| words += 1,
+    /// );
+    /// assert_eq!(words, 5);
+    /// ```
+    #[doc(alias = "enumerateSubstringsInRange:options:usingBlock:")]
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSRange")]
+    pub fn enumerate_substrings_in_range<F: FnMut(Option<&NSString>, NSRange)>(
+        &self,
+        range: NSRange,
+        options: crate::Foundation::NSStringEnumerationOptions,
+        f: F,
+    ) {
+        use core::cell::RefCell;
+        use core::ptr::NonNull;
+
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        // TODO: Replace this check with catching the thrown NSRangeException
+        assert!(
+            range.end() <= self.len_utf16(),
+            "range {range:?} out of bounds of string with UTF-16 length {}",
+            self.len_utf16(),
+        );
+
+        let f = RefCell::new(f);
+        let block = StackBlock::new(
+            |substring: *mut NSString,
+             substring_range: NSRange,
+             _enclosing_range: NSRange,
+             _stop: NonNull<Bool>| {
+                let guard = crate::util::AbortOnUnwind;
+                // SAFETY: The substring is either NULL, or a valid string for
+                // the duration of the block.
+                let substring = unsafe { substring.as_ref() };
+                (f.borrow_mut())(substring, substring_range);
+                core::mem::forget(guard);
+            },
+        );
+        // SAFETY: The block has the correct signature, and is only used for
+        // the duration of the call. The range is checked to be in bounds.
+        let _: () = unsafe {
+            objc2::msg_send![
+                self,
+                enumerateSubstringsInRange: range,
+                options: options,
+                usingBlock: &*block,
+            ]
+        };
+    }
+}
+
 impl NSMutableString {
     /// Creates a new [`NSMutableString`] by copying the given string slice.
     #[doc(alias = "initWithBytes:length:encoding:")]
@@ -139,6 +568,104 @@ impl NSMutableString {
     pub fn from_str(string: &str) -> Id<Self> {
         unsafe { init_with_str(Self::alloc(), string) }
     }
+
+    /// Insert the given string at the given index.
+    ///
+    /// Note that the index is in UTF-16 code units, not in bytes or
+    /// [`char`]s, see [`len_utf16`][NSString::len_utf16].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is larger than the length of the string.
+    #[doc(alias = "insertString:atIndex:")]
+    pub fn insert(&mut self, string: &NSString, index: usize) {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        assert!(
+            index <= self.len_utf16(),
+            "index {index} out of bounds of string with UTF-16 length {}",
+            self.len_utf16(),
+        );
+        // SAFETY: The index is checked to be in bounds.
+        unsafe { self.insertString_atIndex(string, index) }
+    }
+
+    /// Remove the characters in the given range.
+    ///
+    /// Note that the range is in UTF-16 code units, not in bytes or
+    /// [`char`]s, see [`len_utf16`][NSString::len_utf16].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[doc(alias = "deleteCharactersInRange:")]
+    #[cfg(feature = "NSRange")]
+    pub fn delete_characters_in_range(&mut self, range: NSRange) {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        assert!(
+            range.end() <= self.len_utf16(),
+            "range {range:?} out of bounds of string with UTF-16 length {}",
+            self.len_utf16(),
+        );
+        // SAFETY: The range is checked to be in bounds.
+        unsafe { self.deleteCharactersInRange(range) }
+    }
+
+    /// Replace all occurrences of `target` within the given range with
+    /// `replacement`, and return the number of replacements made.
+    ///
+    /// The `options` can be used to e.g. do case-insensitive matching, see
+    /// [`NSStringCompareOptions`].
+    ///
+    /// Note that the range is in UTF-16 code units, not in bytes or
+    /// [`char`]s, see [`len_utf16`][NSString::len_utf16].
+    ///
+    /// [`NSStringCompareOptions`]: crate::Foundation::NSStringCompareOptions
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSMutableString, NSRange, NSStringCompareOptions};
+    ///
+    /// let mut s = NSMutableString::from_str("a-b-c");
+    /// let range = NSRange::new(0, s.len_utf16());
+    /// let options = NSStringCompareOptions::empty();
+    /// let count = s.replace_occurrences(ns_string!("-"), ns_string!("+"), options, range);
+    /// assert_eq!(count, 2);
+    /// assert_eq!(s.to_string(), "a+b+c");
+    /// ```
+    #[doc(alias = "replaceOccurrencesOfString:withString:options:range:")]
+    #[cfg(feature = "NSRange")]
+    pub fn replace_occurrences(
+        &mut self,
+        target: &NSString,
+        replacement: &NSString,
+        options: crate::Foundation::NSStringCompareOptions,
+        range: NSRange,
+    ) -> usize {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        assert!(
+            range.end() <= self.len_utf16(),
+            "range {range:?} out of bounds of string with UTF-16 length {}",
+            self.len_utf16(),
+        );
+        // SAFETY: The range is checked to be in bounds.
+        unsafe {
+            self.replaceOccurrencesOfString_withString_options_range(
+                target,
+                replacement,
+                options,
+                range,
+            )
+        }
+    }
 }
 
 unsafe fn init_with_str<T: Message>(obj: Allocated<T>, string: &str) -> Id<T> {
@@ -260,3 +787,69 @@ impl fmt::Write for NSMutableString {
         Ok(())
     }
 }
+
+/// The error returned when converting an [`NSString`] that is not valid
+/// UTF-16 to a Rust [`String`].
+///
+/// This happens if the string contains an unpaired surrogate.
+#[cfg(feature = "NSRange")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromNSStringError {
+    utf16_index: usize,
+}
+
+#[cfg(feature = "NSRange")]
+impl FromNSStringError {
+    /// The index of the unpaired surrogate, in UTF-16 code units.
+    ///
+    /// See [`NSString::len_utf16`].
+    #[inline]
+    pub fn utf16_index(&self) -> usize {
+        self.utf16_index
+    }
+}
+
+#[cfg(feature = "NSRange")]
+impl fmt::Display for FromNSStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unpaired surrogate found at UTF-16 index {}",
+            self.utf16_index
+        )
+    }
+}
+
+#[cfg(feature = "NSRange")]
+impl std::error::Error for FromNSStringError {}
+
+/// Convert the string to a Rust [`String`], without replacing invalid data.
+///
+/// Unlike the [`fmt::Display`] implementation, this returns an error if the
+/// string contains an unpaired surrogate.
+#[cfg(feature = "NSRange")]
+impl TryFrom<&NSString> for String {
+    type Error = FromNSStringError;
+
+    fn try_from(string: &NSString) -> Result<Self, Self::Error> {
+        let len = string.len_utf16();
+        let mut buf: Vec<u16> = alloc::vec![0; len];
+        let ptr = NonNull::new(buf.as_mut_ptr()).unwrap();
+        // SAFETY: The buffer has room for `len` UTF-16 code units, and the
+        // range is in bounds.
+        unsafe { string.getCharacters_range(ptr, NSRange::new(0, len)) };
+
+        let mut res = String::with_capacity(len);
+        let mut utf16_index = 0;
+        for c in char::decode_utf16(buf) {
+            match c {
+                Ok(c) => {
+                    utf16_index += c.len_utf16();
+                    res.push(c);
+                }
+                Err(_) => return Err(FromNSStringError { utf16_index }),
+            }
+        }
+        Ok(res)
+    }
+}
@@ -5,7 +5,7 @@ use alloc::vec::Vec;
 use alloc::{format, vec};
 use core::ptr;
 
-use crate::Foundation::{NSArray, NSNumber, NSObject};
+use crate::Foundation::{NSArray, NSNumber, NSObject, NSRange};
 use objc2::mutability::IsRetainable;
 use objc2::rc::Id;
 use objc2::runtime::{AnyObject, ProtocolObject};
@@ -69,12 +69,48 @@ fn test_equality() {
     assert_ne!(array1, array2);
 }
 
+#[test]
+fn test_contains_index_of() {
+    let array = NSArray::from_vec(vec![
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(2),
+        NSNumber::new_u8(1),
+    ]);
+
+    assert!(array.contains(&NSNumber::new_u8(1)));
+    assert!(array.contains(&NSNumber::new_u8(2)));
+    assert!(!array.contains(&NSNumber::new_u8(3)));
+
+    // Returns the first index
+    assert_eq!(array.index_of(&NSNumber::new_u8(1)), Some(0));
+    assert_eq!(array.index_of(&NSNumber::new_u8(2)), Some(1));
+    assert_eq!(array.index_of(&NSNumber::new_u8(3)), None);
+
+    let empty_array = <NSArray<NSNumber>>::new();
+    assert!(!empty_array.contains(&NSNumber::new_u8(1)));
+    assert_eq!(empty_array.index_of(&NSNumber::new_u8(1)), None);
+}
+
 #[test]
 fn test_debug() {
     let obj = sample_number_array(0);
     assert_eq!(format!("{obj:?}"), "[]");
     let obj = sample_number_array(3);
     assert_eq!(format!("{obj:?}"), "[0, 1, 2]");
+    assert_eq!(format!("{obj:#?}"), "[\n    0,\n    1,\n    2,\n]");
+}
+
+#[test]
+fn test_debug_precision() {
+    let obj = sample_number_array(3);
+    assert_eq!(format!("{obj:.3?}"), "[0, 1, 2]");
+    assert_eq!(format!("{obj:.2?}"), "[0, 1, ...]");
+    assert_eq!(format!("{obj:.0?}"), "[...]");
+    assert_eq!(format!("{obj:#.1?}"), "[\n    0,\n    ...,\n]");
+
+    // The precision does not affect the elements
+    let obj = NSArray::from_vec(vec![NSNumber::new_f64(1.25), NSNumber::new_f64(2.5)]);
+    assert_eq!(format!("{obj:.1?}"), "[1.25, ...]");
 }
 
 #[test]
@@ -83,10 +119,25 @@ fn test_get() {
     assert_ne!(array.get(0), array.get(3));
     assert_eq!(array.first(), array.get(0));
     assert_eq!(array.last(), array.get(3));
+    assert!(array.get(4).is_none());
+    assert!(array.get_retained(4).is_none());
+    assert_eq!(&*array.get_retained(2).unwrap(), array.get(2).unwrap());
 
     let empty_array = <NSArray<NSObject>>::new();
     assert!(empty_array.first().is_none());
     assert!(empty_array.last().is_none());
+    assert!(empty_array.get(0).is_none());
+    assert!(empty_array.is_empty());
+}
+
+#[test]
+fn test_single_element() {
+    let array = sample_array(1);
+    assert!(!array.is_empty());
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.first(), array.get(0));
+    assert_eq!(array.last(), array.get(0));
+    assert!(array.first().is_some());
 }
 
 #[test]
@@ -158,6 +209,48 @@ fn test_objects_in_range() {
     assert_eq!(all_objs.len(), 4);
 }
 
+#[test]
+fn test_subarray_concat() {
+    let array = sample_number_array(4);
+
+    let sub = array.subarray(NSRange::new(1, 2));
+    assert_eq!(sub.len(), 2);
+    assert_eq!(sub[0], *NSNumber::new_u8(1));
+    assert_eq!(sub[1], *NSNumber::new_u8(2));
+    assert!(array.subarray(NSRange::new(4, 0)).is_empty());
+
+    let joined = sub.concat(&array);
+    assert_eq!(joined.len(), 6);
+    assert_eq!(format!("{joined:?}"), "[1, 2, 0, 1, 2, 3]");
+    assert_eq!(array.concat(&NSArray::<NSNumber>::new()), array);
+}
+
+#[test]
+#[should_panic = "out of bounds"]
+fn test_subarray_out_of_bounds() {
+    let array = sample_number_array(4);
+    let _ = array.subarray(NSRange::new(3, 2));
+}
+
+#[test]
+fn test_get_objects() {
+    let array = sample_array(4);
+    let mut objs = Vec::new();
+
+    array.get_objects(&mut objs);
+    assert_eq!(objs, array.to_vec());
+
+    let array = sample_array(2);
+    array.get_objects(&mut objs);
+    assert_eq!(objs.len(), 2);
+    assert_eq!(objs[0], array.get(0).unwrap());
+    assert_eq!(objs[1], array.get(1).unwrap());
+
+    let empty_array = <NSArray<NSObject>>::new();
+    empty_array.get_objects(&mut objs);
+    assert!(objs.is_empty());
+}
+
 #[test]
 fn test_generic_ownership_traits() {
     fn assert_partialeq<T: PartialEq>() {}
@@ -195,3 +288,215 @@ fn test_access_anyobject() {
     for _ in array.iter_retained() {}
     for _ in array {}
 }
+
+#[test]
+#[cfg(feature = "NSString")]
+fn test_strings_roundtrip() {
+    use crate::Foundation::{ns_string, NSString};
+
+    let array = NSArray::from_strings(&["a", "bcd", "", "æøå", "a"]);
+    assert_eq!(array.len(), 5);
+    assert_eq!(&array[1], ns_string!("bcd"));
+    assert_eq!(&array[3], ns_string!("æøå"));
+    assert_eq!(array.to_vec_strings(), ["a", "bcd", "", "æøå", "a"]);
+
+    let empty = NSArray::<NSString>::from_strings(&[]);
+    assert!(empty.is_empty());
+    assert!(empty.to_vec_strings().is_empty());
+}
+
+#[test]
+#[cfg(feature = "NSString")]
+fn test_joined() {
+    use crate::Foundation::{ns_string, NSString};
+
+    let array = NSArray::from_strings(&["a", "b", "c"]);
+    assert_eq!(&*array.joined(ns_string!(", ")), ns_string!("a, b, c"));
+    assert_eq!(&*array.joined(ns_string!("")), ns_string!("abc"));
+
+    let single = NSArray::from_strings(&["a"]);
+    assert_eq!(&*single.joined(ns_string!(", ")), ns_string!("a"));
+
+    let empty = NSArray::<NSString>::from_strings(&[]);
+    assert_eq!(&*empty.joined(ns_string!(", ")), ns_string!(""));
+}
+
+#[test]
+#[cfg(feature = "NSEnumerator")]
+fn test_map() {
+    let array = sample_number_array(4);
+    let mapped = array.map(|n| NSNumber::new_u8(n.as_u8() + 1));
+    assert_eq!(mapped.len(), 4);
+    let values: Vec<_> = mapped.iter().map(|n| n.as_u8()).collect();
+    assert_eq!(values, [1, 2, 3, 4]);
+
+    let empty = sample_number_array(0).map(|n| NSNumber::new_u8(n.as_u8() + 1));
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[cfg(feature = "NSEnumerator")]
+fn test_filter() {
+    let array = NSArray::from_vec(vec![
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(2),
+        NSNumber::new_u8(3),
+        NSNumber::new_u8(4),
+    ]);
+    let even = array.filter(|n| n.as_u8() % 2 == 0);
+    assert_eq!(even.len(), 2);
+    assert!(ptr::eq(&even[0], &array[1]));
+    assert!(ptr::eq(&even[1], &array[3]));
+
+    assert!(array.filter(|_| false).is_empty());
+}
+
+#[test]
+fn test_from_slice_retains() {
+    use objc2::rc::{__RcTestObject, __ThreadTestData};
+
+    let obj1 = __RcTestObject::new();
+    let obj2 = __RcTestObject::new();
+    let mut expected = __ThreadTestData::current();
+
+    let array = NSArray::from_slice(&[&*obj1, &*obj2]);
+    expected.retain += 2;
+    expected.assert_current();
+    assert!(ptr::eq(&array[0], &*obj1));
+    assert!(ptr::eq(&array[1], &*obj2));
+
+    drop(array);
+    expected.release += 2;
+    expected.assert_current();
+}
+
+#[test]
+fn test_eq_slice() {
+    let a = NSNumber::new_u8(1);
+    let b = NSNumber::new_u8(2);
+    let array = NSArray::from_id_slice(&[a.clone(), b.clone()]);
+
+    assert!(array.eq_slice(&[a.clone(), b.clone()]));
+    assert!(!array.eq_slice(&[a.clone()]));
+    assert!(!array.eq_slice(&[b.clone(), a.clone()]));
+    assert!(!array.eq_slice(&[]));
+
+    // Compared with `isEqual:`, not by pointer
+    assert!(array.eq_slice(&[NSNumber::new_u8(1), NSNumber::new_u8(2)]));
+
+    assert!(*array == [a.clone(), b][..]);
+    assert!(*array != [a][..]);
+    assert!(*NSArray::<NSNumber>::new() == [][..]);
+}
+
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+#[cfg(feature = "block2")]
+fn test_sorted_by() {
+    let array = NSArray::from_vec(vec![
+        NSNumber::new_u8(3),
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(2),
+    ]);
+
+    let mut calls = 0;
+    let sorted = array.sorted_by(|a, b| {
+        calls += 1;
+        b.as_u8().cmp(&a.as_u8())
+    });
+    assert_ne!(calls, 0);
+    assert_eq!(sorted.len(), 3);
+    assert_eq!(sorted[0].as_u8(), 3);
+    assert_eq!(sorted[1].as_u8(), 2);
+    assert_eq!(sorted[2].as_u8(), 1);
+
+    // Unchanged
+    assert_eq!(array[0].as_u8(), 3);
+    assert_eq!(array[1].as_u8(), 1);
+}
+
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+#[cfg(feature = "block2")]
+fn test_comparator_block() {
+    use crate::Foundation::ComparatorBlock;
+
+    let array = NSArray::from_vec(vec![NSNumber::new_u8(2), NSNumber::new_u8(1)]);
+    let comparator = ComparatorBlock::new(|a: &NSNumber, b: &NSNumber| a.as_u8().cmp(&b.as_u8()));
+    let sorted: Id<NSArray<NSNumber>> =
+        unsafe { objc2::msg_send_id![&array, sortedArrayUsingComparator: comparator.as_block()] };
+    assert_eq!(sorted[0].as_u8(), 1);
+    assert_eq!(sorted[1].as_u8(), 2);
+}
+
+#[test]
+#[cfg(feature = "block2")]
+fn test_try_for_each() {
+    use core::ops::ControlFlow;
+
+    let array = sample_number_array(6);
+
+    let mut visited = Vec::new();
+    let res = array.try_for_each(|n| {
+        visited.push(n.as_u8());
+        if n.as_u8() == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(res, ControlFlow::Break(()));
+    assert_eq!(visited, [0, 1, 2]);
+
+    let mut count = 0;
+    let res = array.try_for_each(|_| {
+        count += 1;
+        ControlFlow::Continue(())
+    });
+    assert_eq!(res, ControlFlow::Continue(()));
+    assert_eq!(count, 6);
+
+    let res = sample_number_array(0).try_for_each(|_| ControlFlow::Break(()));
+    assert_eq!(res, ControlFlow::Continue(()));
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSIndexSet")]
+fn test_indexes_passing() {
+    let array = NSArray::from_vec(vec![
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(2),
+        NSNumber::new_u8(3),
+        NSNumber::new_u8(4),
+    ]);
+    let indexes = array.indexes_passing(|n| n.as_u8() % 2 == 0);
+    unsafe {
+        assert_eq!(indexes.count(), 2);
+        assert!(indexes.containsIndex(1));
+        assert!(indexes.containsIndex(3));
+    }
+
+    let indexes = array.indexes_passing(|_| false);
+    assert_eq!(unsafe { indexes.count() }, 0);
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSObjCRuntime")]
+fn test_par_for_each() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let array = sample_number_array(100);
+
+    let count = AtomicUsize::new(0);
+    let sum = AtomicUsize::new(0);
+    array.par_for_each(|n| {
+        count.fetch_add(1, Ordering::Relaxed);
+        sum.fetch_add(n.as_u8() as usize, Ordering::Relaxed);
+    });
+    assert_eq!(count.load(Ordering::Relaxed), 100);
+    assert_eq!(sum.load(Ordering::Relaxed), (0..100).sum::<usize>());
+
+    sample_number_array(0).par_for_each(|_| unreachable!());
+}
@@ -46,6 +46,21 @@ fn test_copy() {
     assert!(s3.is_kind_of::<Foundation::NSMutableAttributedString>());
 }
 
+#[test]
+fn test_copy_not_leaked() {
+    use Foundation::{retain_count, NSCopying, NSMutableCopying};
+
+    let s1 = NSAttributedString::from_nsstring(&NSString::from_str("abc"));
+    // Whether the copy is the same object is undocumented, so only check
+    // that the copies release whatever they retained.
+    let before = retain_count(&*s1);
+    autoreleasepool(|_| {
+        let _s2 = s1.copy();
+        let _s3 = s1.mutableCopy();
+    });
+    assert_eq!(retain_count(&*s1), before);
+}
+
 #[test]
 #[cfg(feature = "NSDictionary")]
 fn test_debug() {
@@ -15,3 +15,22 @@ fn try_running_functions() {
     assert_eq!(format!("{:?}", bundle.infoDictionary().unwrap()), "{}");
     assert_eq!(bundle.name(), None);
 }
+
+#[test]
+#[cfg(feature = "NSString")]
+fn test_missing_resources() {
+    use crate::Foundation::ns_string;
+
+    let bundle = NSBundle::mainBundle();
+    // The test harness is not an application bundle
+    if cfg!(target_os = "macos") {
+        assert_eq!(bundle.bundleIdentifier(), None);
+    }
+
+    let name = ns_string!("does-not-exist");
+    let path = bundle.pathForResource_ofType(Some(name), Some(ns_string!("txt")));
+    assert_eq!(path, None);
+
+    let value = bundle.objectForInfoDictionaryKey(ns_string!("DoesNotExist"));
+    assert!(value.is_none());
+}
@@ -0,0 +1,62 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use std::os::raw::c_void;
+
+use crate::Foundation::CallbackContext;
+
+// Stand-in for a C API that stores a callback and calls it later.
+struct Registration {
+    f: unsafe extern "C" fn(*mut c_void),
+    context: *mut c_void,
+}
+
+impl Registration {
+    fn fire(&self) {
+        unsafe { (self.f)(self.context) }
+    }
+}
+
+#[test]
+fn test_trampoline() {
+    let mut calls = 0;
+    let mut context = CallbackContext::new(|| calls += 1);
+    let registration = Registration {
+        f: context.trampoline(),
+        context: context.as_ptr(),
+    };
+    registration.fire();
+    registration.fire();
+    drop(context);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_trampoline_once() {
+    let value = Rc::new(5);
+    let captured = value.clone();
+    let context = CallbackContext::new(move || assert_eq!(*captured, 5));
+    let registration = Registration {
+        f: context.trampoline_once(),
+        context: context.into_raw(),
+    };
+    assert_eq!(Rc::strong_count(&value), 2);
+
+    // The closure is dropped after it has been called.
+    registration.fire();
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_from_raw() {
+    let value = Rc::new(5);
+    let captured = value.clone();
+    // Use a nameable closure type, so that it can be passed to `from_raw`.
+    let closure: Box<dyn FnOnce()> = Box::new(move || drop(captured));
+    let ptr = CallbackContext::new(closure).into_raw();
+    assert_eq!(Rc::strong_count(&value), 2);
+
+    // A callback that is never called can still be cleaned up.
+    let context = unsafe { CallbackContext::<Box<dyn FnOnce()>>::from_raw(ptr) };
+    drop(context);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
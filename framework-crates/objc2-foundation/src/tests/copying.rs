@@ -1,6 +1,8 @@
+#![cfg(feature = "NSObject")]
 #![cfg(feature = "NSString")]
 use objc2::{rc::Id, runtime::ProtocolObject};
-use objc2_foundation::{NSCopying, NSMutableCopying, NSString};
+
+use crate::Foundation::{NSCopying, NSMutableCopying, NSMutableString, NSString};
 
 #[test]
 fn copy() {
@@ -15,3 +17,57 @@ fn copy_mutable() {
     let protocol_object: &ProtocolObject<dyn NSMutableCopying> = ProtocolObject::from_ref(&*obj);
     let _: Id<ProtocolObject<dyn NSMutableCopying>> = protocol_object.mutableCopy();
 }
+
+/// Copying returns the concrete type, no casting required.
+#[test]
+fn copy_concrete_types() {
+    fn _string(s: &NSString) -> Id<NSString> {
+        s.copy()
+    }
+    fn _string_mutable(s: &NSString) -> Id<NSMutableString> {
+        s.mutableCopy()
+    }
+    fn _mutable_string(s: &NSMutableString) -> Id<NSString> {
+        s.copy()
+    }
+    fn _mutable_string_mutable(s: &NSMutableString) -> Id<NSMutableString> {
+        s.mutableCopy()
+    }
+
+    #[cfg(feature = "NSArray")]
+    {
+        use crate::Foundation::{NSArray, NSMutableArray};
+
+        fn _array(a: &NSArray<NSString>) -> Id<NSArray<NSString>> {
+            a.copy()
+        }
+        fn _array_mutable(a: &NSArray<NSString>) -> Id<NSMutableArray<NSString>> {
+            a.mutableCopy()
+        }
+        fn _mutable_array(a: &NSMutableArray<NSString>) -> Id<NSArray<NSString>> {
+            a.copy()
+        }
+    }
+
+    let s = NSMutableString::from_str("abc");
+    let copied: Id<NSString> = s.copy();
+    assert_eq!(&**s, &*copied);
+}
+
+#[test]
+fn conforms_to_protocol() {
+    use objc2::runtime::{NSObject, NSObjectProtocol};
+    use objc2::ProtocolType;
+
+    let copying = <dyn NSCopying>::protocol().unwrap();
+    let mutable_copying = <dyn NSMutableCopying>::protocol().unwrap();
+
+    let obj = NSString::new();
+    assert!(obj.conformsToProtocol(copying));
+    assert!(obj.conformsToProtocol(mutable_copying));
+    assert!(!obj.isProxy());
+
+    let obj = NSObject::new();
+    assert!(!obj.conformsToProtocol(copying));
+    assert!(!obj.isProxy());
+}
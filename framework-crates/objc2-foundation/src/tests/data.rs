@@ -17,6 +17,53 @@ fn test_no_bytes() {
     assert!(Some(data.bytes()).is_some());
 }
 
+#[test]
+#[cfg(feature = "NSString")]
+fn test_base64() {
+    use crate::Foundation::ns_string;
+
+    let bytes: alloc::vec::Vec<u8> = (0..=255).collect();
+    let data = NSData::with_bytes(&bytes);
+    let encoded = data.to_base64();
+    let decoded = NSData::from_base64(&encoded).unwrap();
+    assert_eq!(decoded.bytes(), bytes);
+
+    let data = NSData::with_bytes(b"hello");
+    assert_eq!(&*data.to_base64(), ns_string!("aGVsbG8="));
+    let decoded = NSData::from_base64(ns_string!("aGVsbG8=")).unwrap();
+    assert_eq!(decoded.bytes(), b"hello");
+
+    assert_eq!(NSData::from_base64(ns_string!("")).unwrap().len(), 0);
+    assert!(NSData::from_base64(ns_string!("not base64!")).is_none());
+}
+
+#[test]
+#[cfg(all(feature = "NSString", feature = "std"))]
+fn test_file_roundtrip() {
+    use crate::Foundation::NSString;
+
+    let path = std::env::temp_dir().join("objc2_foundation_test_file_roundtrip");
+    let ns_path = NSString::from_path(&path).unwrap();
+
+    let data = NSData::with_bytes(b"abc\0def");
+    assert!(data.write_to_file(&ns_path, true));
+    let read = NSData::from_contents_of_file(&ns_path).unwrap();
+    assert_eq!(read.bytes(), b"abc\0def");
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(NSData::from_contents_of_file(&ns_path).is_none());
+    let dir = std::env::temp_dir().join("objc2_foundation_missing_dir/file");
+    let ns_dir = NSString::from_path(&dir).unwrap();
+    assert!(!data.write_to_file(&ns_dir, false));
+}
+
+#[test]
+fn test_to_hex_string() {
+    assert_eq!(NSData::new().to_hex_string(), "");
+    let data = NSData::with_bytes(&[0x00, 0x0f, 0x10, 0xab, 0xff]);
+    assert_eq!(data.to_hex_string(), "000f10abff");
+}
+
 #[cfg(feature = "block2")]
 #[test]
 fn test_from_vec() {
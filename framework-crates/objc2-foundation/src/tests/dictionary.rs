@@ -30,6 +30,16 @@ fn test_get() {
     assert!(dict.get(&string).is_none());
 }
 
+#[test]
+fn test_get_str() {
+    let dict = sample_dict("abcd");
+    let string = NSString::from_str("abcd");
+    assert_eq!(dict.get_str("abcd"), dict.get(&string));
+    assert!(dict.get_str("abcd").is_some());
+    assert!(dict.get_str("abcde").is_none());
+    assert!(dict.get_str("").is_none());
+}
+
 #[test]
 fn test_keys() {
     let dict = sample_dict("abcd");
@@ -94,12 +104,45 @@ fn test_arrays() {
     // assert_eq!(objs.len(), 1);
 }
 
+#[test]
+#[cfg(feature = "block2")]
+fn test_enumerate() {
+    let keys = [
+        NSString::from_str("a"),
+        NSString::from_str("b"),
+        NSString::from_str("c"),
+    ];
+    let dict = NSDictionary::from_id_slice(
+        &[&*keys[0], &*keys[1], &*keys[2]],
+        &[NSObject::new(), NSObject::new(), NSObject::new()],
+    );
+
+    let mut pairs = alloc::vec::Vec::new();
+    dict.enumerate(|key, value| pairs.push((key, value)));
+    assert_eq!(pairs.len(), 3);
+    for (key, value) in pairs {
+        assert_eq!(dict.get(key), Some(value));
+    }
+
+    let mut count = 0;
+    dict.enumerate_while(|_, _| {
+        count += 1;
+        count < 2
+    });
+    assert_eq!(count, 2);
+
+    let empty = NSDictionary::<NSString, NSObject>::new();
+    empty.enumerate(|_, _| panic!("called for empty dictionary"));
+}
+
 #[test]
 fn test_debug() {
     let key = NSString::from_str("a");
     let val = NSString::from_str("b");
     let dict = NSDictionary::from_id_slice(&[&*key], &[val]);
     assert_eq!(format!("{dict:?}"), r#"{"a": "b"}"#);
+    assert_eq!(format!("{dict:.1?}"), r#"{"a": "b"}"#);
+    assert_eq!(format!("{dict:.0?}"), "{...: ...}");
 }
 
 #[test]
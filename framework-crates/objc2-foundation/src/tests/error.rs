@@ -1,8 +1,34 @@
 #![cfg(feature = "NSError")]
 #![cfg(feature = "NSString")]
 use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
-use crate::Foundation::{ns_string, NSCocoaErrorDomain, NSError};
+use objc2::rc::Id;
+use objc2::runtime::AnyObject;
+use objc2::ClassType;
+
+use crate::Foundation::{
+    ns_string, NSCocoaErrorDomain, NSDictionary, NSError, NSErrorUserInfoKey,
+    NSLocalizedFailureReasonErrorKey, NSLocalizedRecoverySuggestionErrorKey, NSString,
+    NSUnderlyingErrorKey,
+};
+
+fn error_with_user_info(
+    code: isize,
+    keys: &[&NSErrorUserInfoKey],
+    values: Vec<Id<AnyObject>>,
+) -> Id<NSError> {
+    let user_info = NSDictionary::from_vec(keys, values);
+    unsafe {
+        NSError::initWithDomain_code_userInfo(
+            NSError::alloc(),
+            ns_string!("MyDomain"),
+            code,
+            Some(&user_info),
+        )
+    }
+}
 
 #[test]
 fn basic() {
@@ -27,3 +53,56 @@ fn custom_domain() {
     };
     assert_eq!(format!("{error}"), expected);
 }
+
+#[test]
+fn failure_reason_and_recovery_suggestion() {
+    let error = NSError::new(42, ns_string!("MyDomain"));
+    assert_eq!(error.localized_recovery_suggestion(), None);
+
+    let error = error_with_user_info(
+        42,
+        &[unsafe { NSLocalizedFailureReasonErrorKey }, unsafe {
+            NSLocalizedRecoverySuggestionErrorKey
+        }],
+        vec![
+            Id::into_super(Id::into_super(NSString::from_str("reason"))),
+            Id::into_super(Id::into_super(NSString::from_str("suggestion"))),
+        ],
+    );
+    assert_eq!(
+        error.localized_failure_reason().as_deref(),
+        Some(ns_string!("reason"))
+    );
+    assert_eq!(
+        error.localized_recovery_suggestion().as_deref(),
+        Some(ns_string!("suggestion"))
+    );
+}
+
+#[test]
+fn underlying_error_chain() {
+    let inner = NSError::new(1, ns_string!("MyDomain"));
+    assert!(inner.underlying_error().is_none());
+
+    let key = unsafe { NSUnderlyingErrorKey };
+    let middle = error_with_user_info(2, &[key], vec![Id::into_super(Id::into_super(inner))]);
+    let outer = error_with_user_info(3, &[key], vec![Id::into_super(Id::into_super(middle))]);
+
+    let mut codes = Vec::new();
+    let mut current = Some(outer);
+    while let Some(error) = current {
+        codes.push(error.code());
+        current = error.underlying_error();
+    }
+    assert_eq!(codes, [3, 2, 1]);
+}
+
+#[test]
+fn underlying_error_not_an_error() {
+    let error = error_with_user_info(
+        1,
+        &[unsafe { NSUnderlyingErrorKey }],
+        vec![Id::into_super(Id::into_super(NSString::from_str("abc")))],
+    );
+    assert!(error.underlying_error().is_none());
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "NSKeyValueCoding")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSValue")]
+use core::ptr;
+
+use objc2::runtime::AnyObject;
+
+use crate::Foundation::{NSKeyValueCodingExt, NSMutableDictionary, NSNumber, NSString};
+
+#[test]
+fn test_dictionary_key_value_coding() {
+    // `NSDictionary` implements key-value coding by looking up the key.
+    let mut dict = NSMutableDictionary::<NSString, AnyObject>::new();
+    let key = NSString::from_str("foo");
+    assert!(unsafe { dict.value_for_key(&key) }.is_none());
+
+    let number = NSNumber::new_i32(42);
+    let value: &AnyObject = &number;
+    unsafe { dict.set_value_for_key_mut(Some(value), &key) };
+    let actual = unsafe { dict.value_for_key(&key) }.unwrap();
+    assert!(ptr::eq(&*actual, value));
+    assert_eq!(dict.len(), 1);
+
+    unsafe { dict.set_value_for_key_mut(None, &key) };
+    assert!(unsafe { dict.value_for_key(&key) }.is_none());
+    assert!(dict.is_empty());
+}
@@ -2,19 +2,27 @@ mod array;
 mod attributed_string;
 mod auto_traits;
 mod bundle;
+mod callback_context;
+mod copying;
 mod data;
 mod dictionary;
 mod error;
 mod exception;
+mod key_value_coding;
 mod lock;
 mod mutable_array;
 mod mutable_data;
 mod mutable_dictionary;
 mod mutable_set;
 mod mutable_string;
+mod notification;
+mod null;
 mod number;
+mod objc_key;
+mod options;
 mod process_info;
 mod proxy;
+mod serde;
 mod set;
 mod string;
 mod thread;
@@ -123,3 +123,88 @@ fn test_sort() {
         assert_eq!(strings[1].as_str(pool), "hello");
     });
 }
+
+#[test]
+#[cfg(feature = "NSIndexSet")]
+#[cfg(feature = "NSString")]
+fn test_dedup() {
+    use Foundation::NSString;
+
+    let strings = ["a", "a", "b", "b", "b", "a", "c", "c"];
+    let mut array =
+        NSMutableArray::from_vec(strings.iter().map(|s| NSString::from_str(s)).collect());
+    array.dedup();
+    assert_eq!(array.len(), 4);
+    autoreleasepool(|pool| {
+        let strings: alloc::vec::Vec<_> = (0..array.len()).map(|i| array[i].as_str(pool)).collect();
+        assert_eq!(strings, ["a", "b", "a", "c"]);
+    });
+
+    let strings = ["a", "A", "b", "B", "bb"];
+    let mut array =
+        NSMutableArray::from_vec(strings.iter().map(|s| NSString::from_str(s)).collect());
+    autoreleasepool(|pool| {
+        array.dedup_by(|s1, s2| s1.as_str(pool).eq_ignore_ascii_case(s2.as_str(pool)));
+        assert_eq!(array.len(), 3);
+        assert_eq!(array[2].as_str(pool), "bb");
+    });
+
+    let mut empty = NSMutableArray::<NSString>::new();
+    empty.dedup_by(|_, _| unreachable!());
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_insertion_index() {
+    let mut array = NSMutableArray::from_vec(vec![
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(3),
+        NSNumber::new_u8(3),
+        NSNumber::new_u8(7),
+    ]);
+    let cmp = |a: &NSNumber, b: &NSNumber| a.as_u8().cmp(&b.as_u8());
+
+    assert_eq!(array.insertion_index(&NSNumber::new_u8(0), cmp), 0);
+    assert_eq!(array.insertion_index(&NSNumber::new_u8(1), cmp), 1);
+    assert_eq!(array.insertion_index(&NSNumber::new_u8(3), cmp), 3);
+    assert_eq!(array.insertion_index(&NSNumber::new_u8(5), cmp), 3);
+    assert_eq!(array.insertion_index(&NSNumber::new_u8(9), cmp), 4);
+
+    for n in [4, 0, 9, 3] {
+        let obj = NSNumber::new_u8(n);
+        let index = array.insertion_index(&obj, cmp);
+        array.insert(index, obj);
+    }
+    let values: alloc::vec::Vec<u8> = (0..array.len()).map(|i| array[i].as_u8()).collect();
+    assert_eq!(values, [0, 1, 3, 3, 3, 4, 7, 9]);
+
+    let empty = NSMutableArray::<NSNumber>::new();
+    assert_eq!(empty.insertion_index(&NSNumber::new_u8(1), cmp), 0);
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_reverse() {
+    let mut array = NSMutableArray::from_vec(vec![
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(2),
+        NSNumber::new_u8(3),
+    ]);
+    array.reverse();
+    let values: alloc::vec::Vec<u8> = (0..array.len()).map(|i| array[i].as_u8()).collect();
+    assert_eq!(values, [3, 2, 1]);
+
+    array.push(NSNumber::new_u8(0));
+    array.reverse();
+    let values: alloc::vec::Vec<u8> = (0..array.len()).map(|i| array[i].as_u8()).collect();
+    assert_eq!(values, [0, 1, 2, 3]);
+
+    let mut single = NSMutableArray::from_vec(vec![NSNumber::new_u8(1)]);
+    single.reverse();
+    assert_eq!(single[0].as_u8(), 1);
+
+    let mut empty = NSMutableArray::<NSNumber>::new();
+    empty.reverse();
+    assert!(empty.is_empty());
+}
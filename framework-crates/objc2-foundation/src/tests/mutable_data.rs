@@ -32,6 +32,18 @@ fn test_append() {
     assert_eq!(data.bytes(), [7, 16, 3, 52]);
 }
 
+#[test]
+fn test_append_data() {
+    let mut data = NSMutableData::with_bytes(&[7, 16]);
+    let other = NSData::with_bytes(&[3, 52]);
+    data.append_data(&other);
+    assert_eq!(data.bytes(), [7, 16, 3, 52]);
+    assert_eq!(other.bytes(), [3, 52]);
+
+    data.append_data(&NSData::new());
+    assert_eq!(data.bytes(), [7, 16, 3, 52]);
+}
+
 #[test]
 fn test_replace() {
     let mut data = NSMutableData::with_bytes(&[7, 16]);
@@ -48,6 +60,13 @@ fn test_replace() {
     assert_eq!(data.bytes(), [8, 17]);
 }
 
+#[test]
+#[should_panic = "out of bounds"]
+fn test_replace_out_of_bounds() {
+    let mut data = NSMutableData::with_bytes(&[7, 16]);
+    data.replace_range(1..3, &[3]);
+}
+
 #[test]
 fn test_from_data() {
     let data = NSData::with_bytes(&[1, 2]);
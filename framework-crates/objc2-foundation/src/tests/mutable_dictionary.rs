@@ -6,7 +6,7 @@ use alloc::vec;
 use objc2::msg_send;
 use objc2::rc::Id;
 
-use crate::Foundation::{self, NSMutableDictionary, NSNumber, NSObject};
+use crate::Foundation::{self, NSDictionary, NSMutableDictionary, NSNumber, NSObject};
 
 fn sample_dict() -> Id<NSMutableDictionary<NSNumber, NSObject>> {
     NSMutableDictionary::from_id_slice(
@@ -87,6 +87,43 @@ fn test_insert() {
     assert_eq!(dict.len(), 3);
 }
 
+#[test]
+fn test_extend_from() {
+    let mut dict = NSMutableDictionary::from_vec(
+        &[&*NSNumber::new_i32(1), &*NSNumber::new_i32(2)],
+        vec![NSNumber::new_i32(10), NSNumber::new_i32(20)],
+    );
+    let other = NSDictionary::from_vec(
+        &[&*NSNumber::new_i32(2), &*NSNumber::new_i32(3)],
+        vec![NSNumber::new_i32(200), NSNumber::new_i32(300)],
+    );
+    dict.extend_from(&other);
+
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict[&NSNumber::new_i32(1)].as_i32(), 10);
+    // Last write wins
+    assert_eq!(dict[&NSNumber::new_i32(2)].as_i32(), 200);
+    assert_eq!(dict[&NSNumber::new_i32(3)].as_i32(), 300);
+    // The other dictionary is left untouched
+    assert_eq!(other.len(), 2);
+}
+
+#[test]
+fn test_extend() {
+    let mut dict = sample_dict();
+    let old = dict.get_retained(&NSNumber::new_i32(1)).unwrap();
+    let new = NSObject::new();
+    dict.extend([
+        (NSNumber::new_i32(1), new.clone()),
+        (NSNumber::new_i32(4), NSObject::new()),
+    ]);
+
+    assert_eq!(dict.len(), 4);
+    let value = dict.get(&NSNumber::new_i32(1)).unwrap();
+    assert_eq!(Id::as_ptr(&new), value as *const NSObject);
+    assert_ne!(Id::as_ptr(&old), value as *const NSObject);
+}
+
 #[test]
 fn test_remove() {
     let mut dict = sample_dict();
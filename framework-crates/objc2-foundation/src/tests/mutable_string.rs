@@ -29,6 +29,80 @@ fn test_append() {
     assert_eq!(&s.to_string(), "abcdefghi");
 }
 
+#[test]
+fn test_insert() {
+    let mut s = NSMutableString::from_str("ac");
+    s.insert(&NSString::from_str("b"), 1);
+    s.insert(&NSString::from_str("d"), 3);
+    s.insert(&NSString::from_str("_"), 0);
+    assert_eq!(&s.to_string(), "_abcd");
+}
+
+#[test]
+#[should_panic = "index 4 out of bounds of string with UTF-16 length 3"]
+fn test_insert_out_of_bounds() {
+    let mut s = NSMutableString::from_str("abc");
+    s.insert(&NSString::from_str("d"), 4);
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_delete_characters() {
+    use crate::Foundation::NSRange;
+
+    let mut s = NSMutableString::from_str("a😀bc");
+    // The emoji is two UTF-16 code units
+    s.delete_characters_in_range(NSRange::new(1, 2));
+    assert_eq!(&s.to_string(), "abc");
+    s.delete_characters_in_range(NSRange::new(3, 0));
+    assert_eq!(&s.to_string(), "abc");
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+#[should_panic = "out of bounds of string with UTF-16 length 3"]
+fn test_delete_characters_out_of_bounds() {
+    use crate::Foundation::NSRange;
+
+    let mut s = NSMutableString::from_str("abc");
+    s.delete_characters_in_range(NSRange::new(2, 2));
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_replace_occurrences() {
+    use crate::Foundation::{ns_string, NSRange, NSStringCompareOptions};
+
+    let mut s = NSMutableString::from_str("Foo foo FOO");
+    let options = NSStringCompareOptions::empty();
+    let count = s.replace_occurrences(
+        ns_string!("foo"),
+        ns_string!("bar"),
+        options,
+        NSRange::new(0, 11),
+    );
+    assert_eq!(count, 1);
+    assert_eq!(&s.to_string(), "Foo bar FOO");
+
+    let options = NSStringCompareOptions::NSCaseInsensitiveSearch;
+    let count = s.replace_occurrences(
+        ns_string!("foo"),
+        ns_string!("baz"),
+        options,
+        NSRange::new(4, 7),
+    );
+    assert_eq!(count, 1);
+    assert_eq!(&s.to_string(), "Foo bar baz");
+
+    let count = s.replace_occurrences(
+        ns_string!("x"),
+        ns_string!("y"),
+        options,
+        NSRange::new(0, 11),
+    );
+    assert_eq!(count, 0);
+}
+
 #[test]
 fn test_set() {
     let mut s = NSMutableString::from_str("abc");
@@ -0,0 +1,47 @@
+#![cfg(feature = "NSNotification")]
+#![cfg(feature = "NSOperation")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "block2")]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use objc2::runtime::AnyObject;
+
+use crate::Foundation::{ns_string, NSNotificationCenter, NSObject};
+
+#[test]
+fn test_observe() {
+    let center = unsafe { NSNotificationCenter::defaultCenter() };
+    let name = ns_string!("Objc2TestNotification");
+    let sender: &AnyObject = &NSObject::new();
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let observer = center.add_observer_for_name(Some(name), None, None, {
+        let count = count.clone();
+        move |notification| {
+            assert_eq!(&*unsafe { notification.name() }, name);
+            count.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    let sender_count = Arc::new(AtomicUsize::new(0));
+    let sender_observer = center.add_observer_for_name(None, Some(sender), None, {
+        let sender_count = sender_count.clone();
+        move |_| {
+            sender_count.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    unsafe { center.postNotificationName_object(name, None) };
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+    assert_eq!(sender_count.load(Ordering::Relaxed), 0);
+
+    unsafe { center.postNotificationName_object(name, Some(sender)) };
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+    assert_eq!(sender_count.load(Ordering::Relaxed), 1);
+
+    center.remove_observer(&observer);
+    center.remove_observer(&sender_observer);
+    unsafe { center.postNotificationName_object(name, Some(sender)) };
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+    assert_eq!(sender_count.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "NSNull")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSString")]
+use core::ptr;
+
+use objc2::rc::Id;
+
+use crate::Foundation::{NSMutableArray, NSNull, NSString};
+
+#[test]
+fn test_option_roundtrip() {
+    let s = NSString::from_str("abc");
+    let mut array = NSMutableArray::new();
+    array.push(NSNull::option_to_object(Some(&*s)));
+    array.push(NSNull::option_to_object(None::<&NSString>));
+    assert_eq!(array.len(), 2);
+
+    let first = NSNull::object_to_option(&array[0]).unwrap();
+    assert!(ptr::eq(first, Id::as_ptr(&s).cast()));
+    assert!(NSNull::object_to_option(&array[1]).is_none());
+}
+
+#[test]
+fn test_null_is_singleton() {
+    let null = NSNull::option_to_object(None::<&NSString>);
+    assert_eq!(Id::as_ptr(&null).cast(), Id::as_ptr(&NSNull::null()));
+}
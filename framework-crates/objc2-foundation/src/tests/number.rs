@@ -69,6 +69,25 @@ fn roundtrip() {
     assert!(NSNumber::new_f64(-f64::NAN).as_f64().is_nan());
 }
 
+#[test]
+fn float_return_values() {
+    use core::f64::consts::PI;
+
+    // On x86, `floatValue` and `doubleValue` must be called with
+    // `objc_msgSend_fpret`; check that we get the known values back.
+    let num = NSNumber::new_f64(PI);
+    assert_eq!(num.as_f64(), PI);
+    assert_eq!(num.as_f32(), PI as f32);
+
+    let num = NSNumber::new_f32(1.5);
+    assert_eq!(num.as_f64(), 1.5);
+    assert_eq!(num.as_f32(), 1.5);
+
+    // Converted by Foundation
+    assert_eq!(NSNumber::new_i32(-7).as_f64(), -7.0);
+    assert_eq!(NSNumber::new_u8(255).as_f32(), 255.0);
+}
+
 #[test]
 fn cast_between_types() {
     assert_eq!(NSNumber::new_bool(true).as_i8(), 1);
@@ -130,3 +149,35 @@ fn display_debug() {
     assert_display_debug(NSNumber::new_bool(true), "1");
     assert_display_debug(NSNumber::new_bool(false), "0");
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn cached_small_integers() {
+    use objc2::rc::Id;
+
+    let a = NSNumber::new_i32_cached(42);
+    let b = NSNumber::new_i32_cached(42);
+    assert_eq!(Id::as_ptr(&a), Id::as_ptr(&b));
+    assert_eq!(a, b);
+    assert_eq!(b.as_i32(), 42);
+    assert_eq!(NSNumber::new_i64_cached(-1).as_i64(), -1);
+    assert_eq!(NSNumber::new_u64_cached(255).as_u64(), 255);
+
+    // Each constructor has its own cache, so the objects have the same
+    // encoding as freshly created ones.
+    let i8 = NSNumber::new_i8_cached(1);
+    let u8 = NSNumber::new_u8_cached(1);
+    assert_eq!(i8.encoding(), NSNumber::new_i8(1).encoding());
+    assert_eq!(u8.encoding(), NSNumber::new_u8(1).encoding());
+    // And they are distinct objects, unless Foundation itself returns the
+    // same object for both.
+    if Id::as_ptr(&NSNumber::new_i8(1)) != Id::as_ptr(&NSNumber::new_u8(1)) {
+        assert_ne!(Id::as_ptr(&i8), Id::as_ptr(&u8));
+    }
+
+    // Values outside the cached range still work
+    assert_eq!(NSNumber::new_i32_cached(-2).as_i32(), -2);
+    assert_eq!(NSNumber::new_i32_cached(256).as_i32(), 256);
+    assert_eq!(NSNumber::new_i64_cached(i64::MAX).as_i64(), i64::MAX);
+    assert_eq!(NSNumber::new_u64_cached(u64::MAX).as_u64(), u64::MAX);
+}
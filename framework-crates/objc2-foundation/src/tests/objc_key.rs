@@ -0,0 +1,37 @@
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+use std::collections::{HashMap, HashSet};
+
+use objc2::rc::Id;
+
+use crate::Foundation::{NSMutableString, NSString, ObjcKey};
+
+#[test]
+fn test_hash_map() {
+    // Long enough to not be a tagged pointer
+    let s1 = NSString::from_str("a string that is somewhat long");
+    let s2 = NSString::from_str("a string that is somewhat long");
+    assert_ne!(Id::as_ptr(&s1), Id::as_ptr(&s2));
+
+    let mut map = HashMap::new();
+    assert_eq!(map.insert(ObjcKey::new(s1), 1), None);
+    assert_eq!(map.insert(ObjcKey::new(s2), 2), Some(1));
+    assert_eq!(map.len(), 1);
+
+    let other = NSString::from_str("another string");
+    map.insert(ObjcKey::new(other.clone()), 3);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&ObjcKey::new(other)], 3);
+}
+
+#[test]
+fn test_dedup_across_mutability() {
+    let s1 = NSString::from_str("abc");
+    let s2 = NSMutableString::from_str("abc");
+    let s2: Id<NSString> = Id::into_super(s2);
+
+    let set: HashSet<_> = [s1, s2].into_iter().map(ObjcKey::new).collect();
+    assert_eq!(set.len(), 1);
+    let key = set.into_iter().next().unwrap();
+    assert_eq!(ObjcKey::into_inner(key).to_string(), "abc");
+}
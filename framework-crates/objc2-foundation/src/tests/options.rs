@@ -0,0 +1,45 @@
+#![cfg(feature = "NSObjCRuntime")]
+use crate::Foundation::NSEnumerationOptions;
+
+#[test]
+fn test_bitwise_ops() {
+    let concurrent = NSEnumerationOptions(1 << 0);
+    let reverse = NSEnumerationOptions(1 << 1);
+
+    let both = concurrent | reverse;
+    assert_eq!(both, NSEnumerationOptions(0b11));
+    assert!(both.contains(concurrent));
+    assert!(both.contains(reverse));
+    assert!(both.contains(NSEnumerationOptions::empty()));
+    assert!(!concurrent.contains(both));
+
+    assert_eq!(both & reverse, reverse);
+    assert!((concurrent & reverse).is_empty());
+
+    let mut flags = NSEnumerationOptions::empty();
+    assert!(flags.is_empty());
+    flags |= reverse;
+    assert_eq!(flags, reverse);
+    flags &= concurrent;
+    assert_eq!(flags, NSEnumerationOptions::empty());
+}
+
+#[test]
+fn test_xor_not() {
+    let concurrent = NSEnumerationOptions(1 << 0);
+    let reverse = NSEnumerationOptions(1 << 1);
+    let both = concurrent | reverse;
+
+    assert_eq!(both ^ reverse, concurrent);
+    assert_eq!(concurrent ^ reverse, both);
+    assert!((both ^ both).is_empty());
+
+    // Clearing a flag
+    assert_eq!(both & !reverse, concurrent);
+    assert!(!(!concurrent).contains(concurrent));
+    assert!((!concurrent).contains(reverse));
+
+    let mut flags = both;
+    flags ^= concurrent;
+    assert_eq!(flags, reverse);
+}
@@ -1,6 +1,7 @@
 #![cfg(feature = "NSString")]
 #![cfg(feature = "NSProcessInfo")]
 use alloc::format;
+use alloc::string::ToString;
 
 use crate::Foundation::NSProcessInfo;
 
@@ -20,3 +21,28 @@ fn encoding_operating_system_version() {
     let info = NSProcessInfo::processInfo();
     let _version = info.operatingSystemVersion();
 }
+
+#[test]
+#[cfg(feature = "NSArray")]
+fn arguments() {
+    let info = NSProcessInfo::processInfo();
+    // The first argument is the path of the executable.
+    assert!(info.arguments().count() >= 1);
+}
+
+#[test]
+#[cfg(feature = "NSDictionary")]
+fn environment() {
+    let info = NSProcessInfo::processInfo();
+    let environment = info.environment();
+    // Set by Cargo when running tests, and not modified afterwards.
+    let expected = std::env::var("CARGO_PKG_NAME").ok();
+    let actual = environment.get_str("CARGO_PKG_NAME").map(|v| v.to_string());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn host_name() {
+    let info = NSProcessInfo::processInfo();
+    let _host_name = info.hostName();
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "serde")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSValue")]
+use alloc::string::ToString;
+
+use serde_json::json;
+
+use crate::Foundation::{DeserializeId, NSNumber, NSString};
+
+#[test]
+fn test_string() {
+    let s = NSString::from_str("a\"b😀");
+    assert_eq!(serde_json::to_value(&*s).unwrap(), json!("a\"b😀"));
+
+    let s = NSString::deserialize_id(json!("a\"b😀")).unwrap();
+    assert_eq!(s.to_string(), "a\"b😀");
+
+    assert!(NSString::deserialize_id(json!(1)).is_err());
+}
+
+#[test]
+fn test_number() {
+    assert_eq!(
+        serde_json::to_value(&*NSNumber::new_i32(-3)).unwrap(),
+        json!(-3)
+    );
+    assert_eq!(
+        serde_json::to_value(&*NSNumber::new_u64(u64::MAX)).unwrap(),
+        json!(u64::MAX)
+    );
+    assert_eq!(
+        serde_json::to_value(&*NSNumber::new_f64(1.5)).unwrap(),
+        json!(1.5)
+    );
+    assert_eq!(
+        serde_json::to_value(&*NSNumber::new_bool(true)).unwrap(),
+        json!(true)
+    );
+    assert_eq!(
+        serde_json::to_value(&*NSNumber::new_bool(false)).unwrap(),
+        json!(false)
+    );
+
+    assert_eq!(NSNumber::deserialize_id(json!(-3)).unwrap().as_i64(), -3);
+    assert_eq!(
+        NSNumber::deserialize_id(json!(u64::MAX)).unwrap().as_u64(),
+        u64::MAX
+    );
+    assert_eq!(NSNumber::deserialize_id(json!(1.5)).unwrap().as_f64(), 1.5);
+    assert!(NSNumber::deserialize_id(json!(true)).unwrap().as_bool());
+
+    assert!(NSNumber::deserialize_id(json!("1")).is_err());
+}
+
+#[test]
+#[cfg(feature = "NSNull")]
+fn test_null() {
+    use crate::Foundation::NSNull;
+
+    assert_eq!(serde_json::to_value(&*NSNull::null()).unwrap(), json!(null));
+    let null = NSNull::deserialize_id(json!(null)).unwrap();
+    assert_eq!(null, NSNull::null());
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSEnumerator")]
+#[cfg(feature = "NSObject")]
+fn test_roundtrip() {
+    use crate::Foundation::{NSArray, NSDictionary};
+
+    let value = json!({
+        "a": [1, 2, 3],
+        "b": [],
+        "c": [4.5],
+    });
+    let dict = NSDictionary::<NSString, NSArray<NSNumber>>::deserialize_id(value.clone()).unwrap();
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.get_str("a").unwrap().len(), 3);
+    assert_eq!(dict.get_str("a").unwrap()[2].as_i32(), 3);
+    assert!(dict.get_str("b").unwrap().is_empty());
+
+    let json = serde_json::to_string(&*dict).unwrap();
+    let roundtripped: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtripped, value);
+
+    let array = NSArray::<NSString>::deserialize_id(json!(["x", "y"])).unwrap();
+    assert_eq!(array.len(), 2);
+    assert_eq!(serde_json::to_value(&*array).unwrap(), json!(["x", "y"]));
+
+    assert!(NSArray::<NSString>::deserialize_id(json!([1])).is_err());
+    assert!(NSDictionary::<NSString, NSNumber>::deserialize_id(json!({ "a": "b" })).is_err());
+}
@@ -196,6 +196,10 @@ fn test_debug() {
         format!("{set:?}").as_str(),
         "{\"one\", \"two\"}" | "{\"two\", \"one\"}"
     ));
+    assert!(matches!(
+        format!("{set:.1?}").as_str(),
+        "{\"one\", ...}" | "{\"two\", ...}"
+    ));
 }
 
 /// This currently works, but we should figure out a way to disallow it!
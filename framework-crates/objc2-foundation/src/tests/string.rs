@@ -1,10 +1,11 @@
 #![cfg(feature = "NSString")]
 use alloc::format;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 
-use objc2::rc::autoreleasepool;
+use objc2::rc::{autoreleasepool, Id};
+use objc2::{msg_send_id, ClassType};
 
-use crate::Foundation::{ns_string, NSString};
+use crate::Foundation::{ns_string, nsstring_format, NSObject, NSString};
 
 #[test]
 fn test_equality() {
@@ -143,6 +144,191 @@ fn test_hash() {
     assert_eq!(hashstate.finish(), hashstate2.finish());
 }
 
+#[test]
+fn test_hash_map_key() {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    map.insert(NSString::from_str("foo"), 1);
+    map.insert(NSString::from_str("bar"), 2);
+
+    // Different objects with the same contents are considered the same key
+    assert_eq!(map.get(&*NSString::from_str("foo")), Some(&1));
+    assert_eq!(map.get(&*NSString::from_str("bar")), Some(&2));
+    assert_eq!(map.get(ns_string!("foo")), Some(&1));
+    assert_eq!(map.get(&*NSString::from_str("baz")), None);
+
+    map.insert(NSString::from_str("foo"), 3);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(ns_string!("foo")), Some(&3));
+}
+
+#[test]
+fn test_format() {
+    let s = nsstring_format!("abc");
+    assert_eq!(s.to_string(), "abc");
+
+    let name = ns_string!("foo");
+    let s = nsstring_format!("{name}: {} {:?}", 42, "bar");
+    assert_eq!(s.to_string(), "foo: 42 \"bar\"");
+
+    let s = nsstring_format!("{:?}", ns_string!("baz"));
+    assert_eq!(s.to_string(), "\"baz\"");
+
+    let obj = NSObject::new();
+    let s = nsstring_format!("{obj:?}");
+    assert_eq!(s.to_string(), format!("{obj:?}"));
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+fn test_components_separated_by() {
+    let s = ns_string!("a,,b");
+    let parts = s.components_separated_by(ns_string!(","));
+    assert_eq!(parts.len(), 3);
+    assert_eq!(&parts[0], ns_string!("a"));
+    assert_eq!(&parts[1], ns_string!(""));
+    assert_eq!(&parts[2], ns_string!("b"));
+
+    let parts = ns_string!("abc").components_separated_by(ns_string!(","));
+    assert_eq!(parts.len(), 1);
+    assert_eq!(&parts[0], ns_string!("abc"));
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSObject")]
+fn test_lines() {
+    let s = ns_string!("a\nb\r\n\nc");
+    let lines: alloc::vec::Vec<_> = s.lines().map(|line| line.to_string()).collect();
+    assert_eq!(lines, ["a", "b", "", "c"]);
+
+    assert_eq!(ns_string!("").lines().count(), 0);
+}
+
+#[test]
+#[cfg(all(feature = "block2", feature = "NSRange"))]
+fn test_enumerate_substrings() {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::Foundation::{NSRange, NSStringEnumerationOptions};
+
+    fn substrings(
+        s: &NSString,
+        range: NSRange,
+        options: NSStringEnumerationOptions,
+    ) -> Vec<String> {
+        let mut res = Vec::new();
+        s.enumerate_substrings_in_range(range, options, |substring, substring_range| {
+            let substring = substring.unwrap();
+            assert_eq!(&*s.substring(substring_range), substring);
+            res.push(substring.to_string());
+        });
+        res
+    }
+
+    let by_words = NSStringEnumerationOptions::NSStringEnumerationByWords;
+    let by_sentences = NSStringEnumerationOptions::NSStringEnumerationBySentences;
+
+    let s = ns_string!("The quick fox. It jumps!");
+    let all = NSRange::new(0, s.len_utf16());
+    let words = substrings(s, all, by_words);
+    assert_eq!(words, ["The", "quick", "fox", "It", "jumps"]);
+    let sentences = substrings(s, all, by_sentences);
+    assert_eq!(sentences, ["The quick fox. ", "It jumps!"]);
+    let words = substrings(s, NSRange::new(4, 9), by_words);
+    assert_eq!(words, ["quick", "fox"]);
+
+    // Composed character sequences are not split
+    let s = ns_string!("e\u{301}\u{1F1E9}\u{1F1F0}a");
+    let all = NSRange::new(0, s.len_utf16());
+    let options = NSStringEnumerationOptions::NSStringEnumerationByComposedCharacterSequences;
+    assert_eq!(substrings(s, all, options).len(), 3);
+
+    let mut count = 0;
+    s.enumerate_substrings_in_range(
+        all,
+        options | NSStringEnumerationOptions::NSStringEnumerationSubstringNotRequired,
+        |substring, _| {
+            assert!(substring.is_none());
+            count += 1;
+        },
+    );
+    assert_eq!(count, 3);
+}
+
+#[test]
+#[cfg(all(feature = "block2", feature = "NSRange"))]
+#[should_panic = "out of bounds"]
+fn test_enumerate_substrings_out_of_bounds() {
+    use crate::Foundation::{NSRange, NSStringEnumerationOptions};
+
+    ns_string!("abc").enumerate_substrings_in_range(
+        NSRange::new(1, 3),
+        NSStringEnumerationOptions::NSStringEnumerationByWords,
+        |_, _| {},
+    );
+}
+
+#[test]
+fn test_case_conversion() {
+    let s = ns_string!("hello wORLD");
+    assert_eq!(&*s.to_uppercase(), ns_string!("HELLO WORLD"));
+    assert_eq!(&*s.to_lowercase(), ns_string!("hello world"));
+    assert_eq!(&*s.to_capitalized(), ns_string!("Hello World"));
+
+    // Case mapping may change the length of the string
+    assert_eq!(&*ns_string!("straße").to_uppercase(), ns_string!("STRASSE"));
+
+    // The non-localized rules are used, so in contrast to the Turkish
+    // locale, `i` is uppercased to `I` (and not `İ`), and the dotless `ı` is
+    // uppercased to `I` as well.
+    assert_eq!(&*ns_string!("i").to_uppercase(), ns_string!("I"));
+    assert_eq!(&*ns_string!("ı").to_uppercase(), ns_string!("I"));
+}
+
+#[test]
+#[cfg(feature = "NSCharacterSet")]
+fn test_trimmed_whitespace() {
+    let s = ns_string!(" \t\n abc def \r\n");
+    assert_eq!(&*s.trimmed_whitespace(), ns_string!("abc def"));
+    assert_eq!(&*ns_string!("abc").trimmed_whitespace(), ns_string!("abc"));
+    assert_eq!(&*ns_string!("  ").trimmed_whitespace(), ns_string!(""));
+}
+
+#[test]
+#[cfg(feature = "NSCharacterSet")]
+fn test_trimming() {
+    use crate::Foundation::NSCharacterSet;
+
+    let set = NSCharacterSet::characterSetWithCharactersInString(ns_string!(".,!?"));
+    let s = ns_string!("?!hello, world...");
+    assert_eq!(&*s.trimming(&set), ns_string!("hello, world"));
+    assert_eq!(&*ns_string!("...").trimming(&set), ns_string!(""));
+
+    let set = NSCharacterSet::decimalDigitCharacterSet();
+    assert_eq!(&*ns_string!("123abc456").trimming(&set), ns_string!("abc"));
+}
+
+#[test]
+#[cfg(all(feature = "NSArray", feature = "NSCharacterSet"))]
+fn test_components_separated_by_charset() {
+    use crate::Foundation::NSCharacterSet;
+
+    let set = NSCharacterSet::characterSetWithCharactersInString(ns_string!(",;"));
+    let parts = ns_string!("a,b;c,,d").components_separated_by_charset(&set);
+    let expected = ["a", "b", "c", "", "d"];
+    assert_eq!(parts.len(), expected.len());
+    for (i, expected) in expected.iter().enumerate() {
+        assert_eq!(parts[i].to_string(), *expected);
+    }
+
+    let set = NSCharacterSet::alphanumericCharacterSet();
+    let parts = ns_string!("ab").components_separated_by_charset(&set);
+    assert_eq!(parts.len(), 3);
+}
+
 #[test]
 fn test_prefix_suffix() {
     let s = NSString::from_str("abcdef");
@@ -152,6 +338,95 @@ fn test_prefix_suffix() {
     assert!(s.hasSuffix(&suffix));
     assert!(!s.hasPrefix(&suffix));
     assert!(!s.hasSuffix(&prefix));
+
+    assert!(s.has_prefix(&prefix));
+    assert!(s.has_suffix(&suffix));
+    assert!(!s.has_prefix(&suffix));
+    assert!(!s.has_suffix(&prefix));
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_substring_range_of() {
+    use crate::Foundation::NSRange;
+
+    let s = ns_string!("abcdef");
+    assert_eq!(&*s.substring(NSRange::from(1..4)), ns_string!("bcd"));
+    assert_eq!(&*s.substring(NSRange::from(6..6)), ns_string!(""));
+
+    assert_eq!(s.range_of(ns_string!("cd")), Some(NSRange::from(2..4)));
+    assert_eq!(s.range_of(ns_string!("x")), None);
+    assert_eq!(s.range_of(ns_string!("")), None);
+
+    // The ranges are in UTF-16 code units
+    let s = ns_string!("æbc");
+    assert_eq!(s.range_of(ns_string!("bc")), Some(NSRange::from(1..3)));
+    assert_eq!(&*s.substring(NSRange::from(0..1)), ns_string!("æ"));
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_find_rfind() {
+    let s = ns_string!("hello");
+    assert_eq!(s.find(ns_string!("lo")), Some(3));
+    assert_eq!(s.find(ns_string!("l")), Some(2));
+    assert_eq!(s.rfind(ns_string!("l")), Some(3));
+    assert_eq!(s.rfind(ns_string!("hello")), Some(0));
+    assert_eq!(s.find(ns_string!("x")), None);
+    assert_eq!(s.rfind(ns_string!("x")), None);
+    assert_eq!(s.find(ns_string!("")), None);
+    assert_eq!(s.rfind(ns_string!("")), None);
+
+    // The indices are in UTF-16 code units
+    let s = ns_string!("😀a😀a");
+    assert_eq!(s.find(ns_string!("a")), Some(2));
+    assert_eq!(s.rfind(ns_string!("a")), Some(5));
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+#[should_panic = "out of bounds"]
+fn test_substring_out_of_bounds() {
+    use crate::Foundation::NSRange;
+
+    let _ = ns_string!("abc").substring(NSRange::from(2..4));
+}
+
+#[test]
+#[cfg(all(feature = "NSLocale", feature = "NSObjCRuntime", feature = "NSRange"))]
+fn test_compare_with_locale() {
+    use crate::Foundation::{NSComparisonResult, NSLocale, NSStringCompareOptions};
+
+    let swedish = NSLocale::localeWithLocaleIdentifier(ns_string!("sv_SE"));
+    assert_eq!(&*swedish.localeIdentifier(), ns_string!("sv_SE"));
+    assert_eq!(&*swedish.languageCode(), ns_string!("sv"));
+    let german = NSLocale::localeWithLocaleIdentifier(ns_string!("de_DE"));
+    let none = NSStringCompareOptions::empty();
+
+    // "ä" is sorted after "z" in Swedish, but together with "a" in German
+    let a = ns_string!("ä");
+    let z = ns_string!("z");
+    assert_eq!(
+        a.compare_with_locale(z, none, Some(&swedish)),
+        NSComparisonResult::Descending
+    );
+    assert_eq!(
+        a.compare_with_locale(z, none, Some(&german)),
+        NSComparisonResult::Ascending
+    );
+
+    let case_insensitive = NSStringCompareOptions::NSCaseInsensitiveSearch;
+    assert_eq!(
+        ns_string!("ABC").compare_with_locale(ns_string!("abc"), case_insensitive, None),
+        NSComparisonResult::Same
+    );
+    let numeric = NSStringCompareOptions::NSNumericSearch;
+    assert_eq!(
+        ns_string!("file9").compare_with_locale(ns_string!("file10"), numeric, Some(&german)),
+        NSComparisonResult::Ascending
+    );
+
+    let _ = NSLocale::currentLocale().localeIdentifier();
 }
 
 #[test]
@@ -256,3 +531,68 @@ fn test_macro_in_unsafe() {
     };
     assert_eq!(s.to_string(), "abc");
 }
+
+#[test]
+fn test_from_str_autoreleased() {
+    autoreleasepool(|pool| {
+        let s = NSString::from_str_autoreleased("abc", pool);
+        assert_eq!(s.as_str(pool), "abc");
+        assert_eq!(s, ns_string!("abc"));
+    });
+}
+
+#[test]
+fn test_class_name() {
+    // The actual class is usually a private subclass, such as
+    // `__NSCFString`, `NSTaggedPointerString` or `GSCInlineString`.
+    let s = NSString::from_str("abc");
+    assert!(s.class_name().contains("String"), "{}", s.class_name());
+}
+
+#[test]
+#[cfg(feature = "NSRange")]
+fn test_try_into_string() {
+    let s = ns_string!("abc ♥ 😀");
+    assert_eq!(String::try_from(s).unwrap(), "abc ♥ 😀");
+    assert_eq!(String::try_from(&*NSString::new()).unwrap(), "");
+
+    // An unpaired high surrogate after a surrogate pair.
+    let chars: [u16; 5] = [0x61, 0xD83D, 0xDE00, 0xD800, 0x62];
+    let s: Id<NSString> = unsafe {
+        msg_send_id![
+            NSString::class(),
+            stringWithCharacters: chars.as_ptr(),
+            length: chars.len(),
+        ]
+    };
+    let err = String::try_from(&*s).unwrap_err();
+    assert_eq!(err.utf16_index(), 3);
+    assert_eq!(
+        err.to_string(),
+        "unpaired surrogate found at UTF-16 index 3"
+    );
+}
+
+#[test]
+#[cfg(feature = "NSPathUtilities")]
+fn test_path_components() {
+    let path = ns_string!("/tmp/").appending_path_component(ns_string!("image.tiff"));
+    assert_eq!(path.to_string(), "/tmp/image.tiff");
+    assert_eq!(path.last_path_component().to_string(), "image.tiff");
+    assert_eq!(path.path_extension().to_string(), "tiff");
+    assert_eq!(path.deleting_last_path_component().to_string(), "/tmp");
+
+    assert_eq!(ns_string!("/tmp/").last_path_component().to_string(), "tmp");
+    assert_eq!(ns_string!("/").last_path_component().to_string(), "/");
+    assert_eq!(ns_string!("/tmp").path_extension().to_string(), "");
+    assert_eq!(
+        ns_string!("/tmp")
+            .deleting_last_path_component()
+            .to_string(),
+        "/"
+    );
+    assert_eq!(
+        ns_string!("tmp").deleting_last_path_component().to_string(),
+        ""
+    );
+}
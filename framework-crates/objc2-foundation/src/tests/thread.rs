@@ -123,3 +123,37 @@ fn test_main_thread_bound_into_inner() {
     drop(foo);
     assert!(is_dropped.get());
 }
+
+#[test]
+#[cfg(feature = "dispatch")]
+#[cfg(all(feature = "NSRunLoop", feature = "NSDate"))]
+fn test_dispatch_to_main() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use crate::Foundation::{dispatch_to_main, NSDate, NSRunLoop};
+
+    // We can only spin the main runloop when running on the main thread,
+    // which is only the case with `--test-threads=1`.
+    if !is_main_thread() {
+        return;
+    }
+
+    let ran = Arc::new(AtomicBool::new(false));
+    let ran_clone = Arc::clone(&ran);
+    dispatch_to_main(move |_mtm| {
+        assert!(is_main_thread());
+        ran_clone.store(true, Ordering::SeqCst);
+    });
+    // Not run synchronously, even though we're on the main thread
+    assert!(!ran.load(Ordering::SeqCst));
+
+    let run_loop = unsafe { NSRunLoop::mainRunLoop() };
+    for _ in 0..100 {
+        if ran.load(Ordering::SeqCst) {
+            break;
+        }
+        unsafe { run_loop.runUntilDate(&NSDate::dateWithTimeIntervalSinceNow(0.01)) };
+    }
+    assert!(ran.load(Ordering::SeqCst));
+}
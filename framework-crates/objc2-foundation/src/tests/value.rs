@@ -13,6 +13,35 @@ fn basic() {
     assert_eq!(unsafe { val.get::<u32>() }, 13);
 }
 
+#[test]
+fn custom_struct() {
+    use objc2::encode::{Encode, Encoding};
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vector3 {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    unsafe impl Encode for Vector3 {
+        const ENCODING: Encoding =
+            Encoding::Struct("Vector3", &[f64::ENCODING, f64::ENCODING, f64::ENCODING]);
+    }
+
+    let vector = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let val = NSValue::new(vector);
+    assert!(val.contains_encoding::<Vector3>());
+    assert!(!val.contains_encoding::<[f64; 3]>());
+    assert!(!val.contains_encoding::<f64>());
+    assert_eq!(unsafe { val.get::<Vector3>() }, vector);
+}
+
 #[test]
 fn test_equality() {
     let val1 = NSValue::new(123u32);
@@ -92,6 +92,44 @@ where
     }
 }
 
+/// Submit the given closure to run asynchronously on the main thread.
+///
+/// Unlike [`run_on_main`], this returns immediately without waiting for the
+/// closure to run, and the closure is always enqueued, even if the current
+/// thread is the main thread. This makes it suitable for e.g. updating the
+/// UI from a background thread.
+///
+/// The closure is passed a [`MainThreadMarker`] that it can further use
+/// to access APIs that are only accessible from the main thread.
+///
+/// The closure is only run once the main thread's event loop gets to it,
+/// see [`run_on_main`] for the requirements on that.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use objc2_foundation::dispatch_to_main;
+/// std::thread::spawn(|| {
+///     dispatch_to_main(|mtm| {
+///         // Do something on the main thread with the given marker
+///     });
+/// });
+/// ```
+#[doc(alias = "dispatch_async")]
+#[doc(alias = "performSelectorOnMainThread:withObject:waitUntilDone:")]
+#[cfg(feature = "dispatch")]
+#[cfg(feature = "NSThread")]
+pub fn dispatch_to_main<F>(f: F)
+where
+    F: Send + FnOnce(MainThreadMarker) + 'static,
+{
+    dispatch::Queue::main().exec_async(move || {
+        // SAFETY: The closure is submitted to run on the main thread.
+        f(unsafe { MainThreadMarker::new_unchecked() })
+    })
+}
+
 /// A marker type taken by functions that can only be executed on the main
 /// thread.
 ///
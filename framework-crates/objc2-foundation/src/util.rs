@@ -1,10 +1,24 @@
 #![allow(dead_code)]
+use core::fmt;
 use core::ptr::NonNull;
 
 use objc2::mutability::IsIdCloneable;
 use objc2::rc::Id;
 use objc2::Message;
 
+/// Convert an index returned by Foundation to [`None`] if it is
+/// `NSNotFound`.
+///
+/// Uses `NSIntegerMax` directly, since the `NSNotFound` static is only
+/// available with the `"NSObjCRuntime"` feature.
+pub(crate) fn not_found_to_none(index: usize) -> Option<usize> {
+    if index == objc2::ffi::NSIntegerMax as usize {
+        None
+    } else {
+        Some(index)
+    }
+}
+
 pub(crate) fn id_ptr_cast<T: ?Sized>(objects: *mut Id<T>) -> *mut NonNull<T> {
     // SAFETY: `Id<T>` has the same memory layout as `NonNull<T>`, and
     // stronger guarantees.
@@ -96,3 +110,36 @@ where
     // convert it to `Id<T>`.
     unsafe { Id::retain(obj as *const T as *mut T).unwrap_unchecked() }
 }
+
+/// Debug-format the inner value, without passing on the precision.
+///
+/// Used by collections, which use the precision to limit the number of
+/// elements that are printed, and don't want it to affect the elements
+/// themselves.
+pub(crate) struct DebugWithoutPrecision<T>(pub(crate) T);
+
+impl<T: fmt::Debug> fmt::Debug for DebugWithoutPrecision<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?}", self.0)
+        } else {
+            write!(f, "{:?}", self.0)
+        }
+    }
+}
+
+/// Aborts the process if dropped while unwinding.
+///
+/// Used in callbacks from Objective-C, since unwinding into Objective-C is
+/// not allowed. Must be [`forget`]-ed once the callback has run.
+///
+/// [`forget`]: core::mem::forget
+pub(crate) struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    #[inline]
+    fn drop(&mut self) {
+        // Panicking while unwinding aborts the process.
+        panic!("a Rust callback panicked, aborting");
+    }
+}
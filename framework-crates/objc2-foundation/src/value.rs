@@ -152,6 +152,11 @@ impl NSValue {
         Some(unsafe { CStr::from_ptr(ptr) }.to_str().unwrap())
     }
 
+    /// Whether the stored value has an encoding equivalent to that of `T`.
+    ///
+    /// See [`get`][Self::get] for why this does not on its own make it safe
+    /// to retrieve the value as `T`.
+    #[doc(alias = "objCType")]
     pub fn contains_encoding<T: 'static + Copy + Encode>(&self) -> bool {
         T::ENCODING.equivalent_to_str(self.encoding().unwrap())
     }